@@ -0,0 +1,169 @@
+//! Per-evaluation overhead of the SDK, with a provider answering right away and no hooks.
+//!
+//! Run with `cargo bench --bench evaluation`.
+
+use criterion::{criterion_group, criterion_main, Criterion};
+use open_feature::{
+    provider::{
+        FeatureProvider, ProviderMetadata, ProviderStatus, ResolutionDetails, SyncFeatureProvider,
+        SyncProvider,
+    },
+    EvaluationContext, EvaluationResult, OpenFeature, StructValue,
+};
+
+struct StaticProvider(ProviderMetadata);
+
+impl Default for StaticProvider {
+    fn default() -> Self {
+        Self(ProviderMetadata::new("Static Provider"))
+    }
+}
+
+#[async_trait::async_trait]
+impl FeatureProvider for StaticProvider {
+    fn metadata(&self) -> &ProviderMetadata {
+        &self.0
+    }
+
+    fn status(&self) -> ProviderStatus {
+        ProviderStatus::Ready
+    }
+
+    async fn resolve_bool_value(
+        &self,
+        _flag_key: &str,
+        _evaluation_context: &EvaluationContext,
+    ) -> EvaluationResult<ResolutionDetails<bool>> {
+        Ok(ResolutionDetails::new(true))
+    }
+
+    async fn resolve_int_value(
+        &self,
+        _flag_key: &str,
+        _evaluation_context: &EvaluationContext,
+    ) -> EvaluationResult<ResolutionDetails<i64>> {
+        Ok(ResolutionDetails::new(0))
+    }
+
+    async fn resolve_float_value(
+        &self,
+        _flag_key: &str,
+        _evaluation_context: &EvaluationContext,
+    ) -> EvaluationResult<ResolutionDetails<f64>> {
+        Ok(ResolutionDetails::new(0.0))
+    }
+
+    async fn resolve_string_value(
+        &self,
+        _flag_key: &str,
+        _evaluation_context: &EvaluationContext,
+    ) -> EvaluationResult<ResolutionDetails<String>> {
+        Ok(ResolutionDetails::new(String::new()))
+    }
+
+    async fn resolve_struct_value(
+        &self,
+        _flag_key: &str,
+        _evaluation_context: &EvaluationContext,
+    ) -> EvaluationResult<ResolutionDetails<StructValue>> {
+        Ok(ResolutionDetails::new(StructValue::default()))
+    }
+}
+
+/// The same provider, resolving through [`SyncProvider`] without futures.
+struct SyncStaticProvider(ProviderMetadata);
+
+impl SyncFeatureProvider for SyncStaticProvider {
+    fn metadata(&self) -> &ProviderMetadata {
+        &self.0
+    }
+
+    fn resolve_bool_value(
+        &self,
+        _flag_key: &str,
+        _evaluation_context: &EvaluationContext,
+    ) -> EvaluationResult<ResolutionDetails<bool>> {
+        Ok(ResolutionDetails::new(true))
+    }
+
+    fn resolve_int_value(
+        &self,
+        _flag_key: &str,
+        _evaluation_context: &EvaluationContext,
+    ) -> EvaluationResult<ResolutionDetails<i64>> {
+        Ok(ResolutionDetails::new(0))
+    }
+
+    fn resolve_float_value(
+        &self,
+        _flag_key: &str,
+        _evaluation_context: &EvaluationContext,
+    ) -> EvaluationResult<ResolutionDetails<f64>> {
+        Ok(ResolutionDetails::new(0.0))
+    }
+
+    fn resolve_string_value(
+        &self,
+        _flag_key: &str,
+        _evaluation_context: &EvaluationContext,
+    ) -> EvaluationResult<ResolutionDetails<String>> {
+        Ok(ResolutionDetails::new(String::new()))
+    }
+
+    fn resolve_struct_value(
+        &self,
+        _flag_key: &str,
+        _evaluation_context: &EvaluationContext,
+    ) -> EvaluationResult<ResolutionDetails<StructValue>> {
+        Ok(ResolutionDetails::new(StructValue::default()))
+    }
+}
+
+fn evaluation(c: &mut Criterion) {
+    let runtime = tokio::runtime::Builder::new_current_thread()
+        .build()
+        .unwrap();
+
+    let (client, sync_client) = runtime.block_on(async {
+        let mut api = OpenFeature::default();
+        api.set_provider(StaticProvider::default()).await;
+        api.set_named_provider(
+            "sync",
+            SyncProvider::new(SyncStaticProvider(ProviderMetadata::new(
+                "Sync Static Provider",
+            ))),
+        )
+        .await;
+        api.set_evaluation_context(
+            EvaluationContext::default()
+                .with_targeting_key("user-1")
+                .with_custom_field("plan", "pro"),
+        )
+        .await;
+        (api.create_client(), api.create_named_client("sync"))
+    });
+
+    let evaluation_context = EvaluationContext::default().with_custom_field("country", "NL");
+
+    let mut group = c.benchmark_group("evaluation");
+
+    group.bench_function("zero_hooks", |b| {
+        b.to_async(&runtime)
+            .iter(|| client.get_bool_value("flag", None, None));
+    });
+
+    group.bench_function("zero_hooks_with_context", |b| {
+        b.to_async(&runtime)
+            .iter(|| client.get_bool_value("flag", Some(&evaluation_context), None));
+    });
+
+    group.bench_function("zero_hooks_sync_provider", |b| {
+        b.to_async(&runtime)
+            .iter(|| sync_client.get_bool_value("flag", None, None));
+    });
+
+    group.finish();
+}
+
+criterion_group!(benches, evaluation);
+criterion_main!(benches);
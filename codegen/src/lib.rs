@@ -0,0 +1,458 @@
+//! Generate typed [OpenFeature](https://docs.rs/open-feature) flag accessors from a flag
+//! manifest, in build scripts.
+//!
+//! The manifest lists the flags of an application:
+//!
+//! ```json
+//! {
+//!   "flags": [
+//!     {
+//!       "key": "new-checkout",
+//!       "type": "bool",
+//!       "default": false,
+//!       "description": "Serve the new checkout flow."
+//!     },
+//!     {
+//!       "key": "checkout-title",
+//!       "type": "string",
+//!       "default": "Checkout",
+//!       "deprecated": "Use the title of the new checkout flow."
+//!     }
+//!   ]
+//! }
+//! ```
+//!
+//! Every flag becomes a `Flag` constant and an async accessor wrapping `Client::get`, documented
+//! with its description. Deprecated flags are marked `#[deprecated]`, and a flag removed from the
+//! manifest fails the build wherever it is still used.
+//!
+//! ```no_run
+//! // build.rs, in `fn main`
+//! let out_dir = std::env::var("OUT_DIR").unwrap();
+//!
+//! open_feature_codegen::compile("flags.json", format!("{out_dir}/flags.rs")).unwrap();
+//! ```
+//!
+//! ```ignore
+//! // src/flags.rs
+//! include!(concat!(env!("OUT_DIR"), "/flags.rs"));
+//!
+//! // Anywhere else
+//! if flags::new_checkout(&client, &evaluation_context).await {
+//!     // ...
+//! }
+//! ```
+#![warn(missing_docs)]
+
+use std::{collections::HashSet, fmt, fs, io, path::Path};
+
+use serde_json::Value;
+
+// ============================================================
+//  Error
+// ============================================================
+
+/// The errors of the code generation.
+#[derive(Debug)]
+pub enum Error {
+    /// The manifest could not be read, or the module could not be written.
+    Io(io::Error),
+
+    /// The manifest is not valid JSON.
+    Json(serde_json::Error),
+
+    /// The manifest does not describe a valid list of flags.
+    Manifest(String),
+}
+
+impl fmt::Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Io(error) => write!(f, "I/O error: {error}"),
+            Self::Json(error) => write!(f, "Invalid JSON: {error}"),
+            Self::Manifest(message) => write!(f, "Invalid manifest: {message}"),
+        }
+    }
+}
+
+impl std::error::Error for Error {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            Self::Io(error) => Some(error),
+            Self::Json(error) => Some(error),
+            Self::Manifest(_) => None,
+        }
+    }
+}
+
+impl From<io::Error> for Error {
+    fn from(error: io::Error) -> Self {
+        Self::Io(error)
+    }
+}
+
+impl From<serde_json::Error> for Error {
+    fn from(error: serde_json::Error) -> Self {
+        Self::Json(error)
+    }
+}
+
+// ============================================================
+//  Generation
+// ============================================================
+
+/// Generate the module of the manifest at `manifest_path` into `out_path`.
+///
+/// Meant to be called from a build script: Cargo is told to rerun it whenever the manifest
+/// changes.
+///
+/// # Errors
+///
+/// Returns an error if the manifest cannot be read or is invalid, or if the module cannot be
+/// written.
+pub fn compile(manifest_path: impl AsRef<Path>, out_path: impl AsRef<Path>) -> Result<(), Error> {
+    let manifest_path = manifest_path.as_ref();
+    println!("cargo:rerun-if-changed={}", manifest_path.display());
+
+    let module = generate(&fs::read_to_string(manifest_path)?)?;
+    fs::write(out_path, module)?;
+
+    Ok(())
+}
+
+/// Generate the source of a `flags` module from the JSON `manifest`.
+///
+/// # Errors
+///
+/// Returns an error if the manifest is invalid, e.g. a flag has no key, a default value of the
+/// wrong type, or the same accessor name as another flag.
+pub fn generate(manifest: &str) -> Result<String, Error> {
+    let manifest: Value = serde_json::from_str(manifest)?;
+    let flags = manifest
+        .get("flags")
+        .and_then(Value::as_array)
+        .ok_or_else(|| Error::Manifest("expected a \"flags\" array".to_string()))?;
+
+    let mut names = HashSet::new();
+    let mut module = String::from(
+        "// @generated by open-feature-codegen from the flag manifest, do not edit.\n\n\
+         /// The typed flags of the flag manifest.\n\
+         #[allow(dead_code)]\n\
+         pub mod flags {\n",
+    );
+
+    for (index, flag) in flags.iter().enumerate() {
+        let flag = Flag::parse(flag)?;
+
+        if !names.insert(flag.name.clone()) {
+            return Err(Error::Manifest(format!(
+                "flag \"{}\" has the same accessor name as another flag: {}",
+                flag.key, flag.name
+            )));
+        }
+
+        if index > 0 {
+            module.push('\n');
+        }
+        module.push_str(&flag.to_string());
+    }
+
+    module.push_str("}\n");
+
+    Ok(module)
+}
+
+// ============================================================
+//  Flag
+// ============================================================
+
+struct Flag {
+    key: String,
+    name: String,
+    declared_type: &'static str,
+    value_type: &'static str,
+    default_value: String,
+    description: Option<String>,
+    deprecated: bool,
+    deprecation_note: Option<String>,
+}
+
+impl Flag {
+    fn parse(flag: &Value) -> Result<Self, Error> {
+        let key = flag
+            .get("key")
+            .and_then(Value::as_str)
+            .filter(|key| !key.is_empty())
+            .ok_or_else(|| Error::Manifest(format!("expected a \"key\" string in {flag}")))?;
+        let invalid = |message: &str| Error::Manifest(format!("flag \"{key}\" {message}"));
+
+        let flag_type = flag
+            .get("type")
+            .and_then(Value::as_str)
+            .ok_or_else(|| invalid("has no \"type\""))?;
+        let default_value = flag
+            .get("default")
+            .ok_or_else(|| invalid("has no \"default\" value"))?;
+
+        let (declared_type, value_type, default_value) = match flag_type {
+            "bool" | "boolean" => (
+                "bool",
+                "bool",
+                default_value.as_bool().map(|value| value.to_string()),
+            ),
+            "int" | "integer" => (
+                "i64",
+                "i64",
+                default_value.as_i64().map(|value| format!("{value}")),
+            ),
+            "float" | "number" => (
+                "f64",
+                "f64",
+                default_value.as_f64().map(|value| format!("{value:?}")),
+            ),
+            "string" => (
+                "&'static str",
+                "::std::string::String",
+                default_value.as_str().map(|value| format!("{value:?}")),
+            ),
+            _ => {
+                return Err(invalid(&format!(
+                    "has an unsupported type \"{flag_type}\", expected bool, int, float or string"
+                )))
+            }
+        };
+        let default_value = default_value
+            .ok_or_else(|| invalid(&format!("has a default value that is not a {flag_type}")))?;
+
+        let description = match flag.get("description") {
+            None | Some(Value::Null) => None,
+            Some(Value::String(description)) => Some(description.clone()),
+            Some(_) => return Err(invalid("has a \"description\" that is not a string")),
+        };
+        let (deprecated, deprecation_note) = match flag.get("deprecated") {
+            None | Some(Value::Null) => (false, None),
+            Some(Value::Bool(deprecated)) => (*deprecated, None),
+            Some(Value::String(note)) => (true, Some(note.clone())),
+            Some(_) => {
+                return Err(invalid(
+                    "has a \"deprecated\" that is not a bool or a string",
+                ))
+            }
+        };
+
+        Ok(Self {
+            name: identifier(key).ok_or_else(|| invalid("has no alphanumeric character"))?,
+            key: key.to_string(),
+            declared_type,
+            value_type,
+            default_value,
+            description,
+            deprecated,
+            deprecation_note,
+        })
+    }
+
+    fn write_attributes(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if let Some(description) = &self.description {
+            for line in description.lines().map(str::trim_end) {
+                if line.is_empty() {
+                    writeln!(f, "    ///")?;
+                } else {
+                    writeln!(f, "    /// {line}")?;
+                }
+            }
+            writeln!(f, "    ///")?;
+        }
+        writeln!(f, "    /// Flag key: `{}`.", self.key)?;
+
+        match &self.deprecation_note {
+            Some(note) => writeln!(f, "    #[deprecated(note = {note:?})]"),
+            None if self.deprecated => writeln!(f, "    #[deprecated]"),
+            None => Ok(()),
+        }
+    }
+}
+
+impl fmt::Display for Flag {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let constant = self.name.trim_start_matches("r#").to_uppercase();
+
+        self.write_attributes(f)?;
+        writeln!(
+            f,
+            "    pub const {constant}: ::open_feature::Flag<{}> =\n        \
+             ::open_feature::Flag::new({:?}, {});\n",
+            self.declared_type, self.key, self.default_value
+        )?;
+
+        self.write_attributes(f)?;
+        if self.deprecated {
+            // INFO: Only the callers of the accessor are warned, not the accessor itself
+            writeln!(f, "    #[allow(deprecated)]")?;
+        }
+        writeln!(
+            f,
+            "    pub async fn {}(\n        \
+             client: &::open_feature::Client,\n        \
+             evaluation_context: &::open_feature::EvaluationContext,\n    \
+             ) -> {} {{\n        \
+             client.get(&{constant}, Some(evaluation_context)).await\n    \
+             }}",
+            self.name, self.value_type
+        )
+    }
+}
+
+// ============================================================
+//  Identifiers
+// ============================================================
+
+const KEYWORDS: &[&str] = &[
+    "abstract", "as", "async", "await", "become", "box", "break", "const", "continue", "do", "dyn",
+    "else", "enum", "extern", "false", "final", "fn", "for", "gen", "if", "impl", "in", "let",
+    "loop", "macro", "match", "mod", "move", "mut", "override", "priv", "pub", "ref", "return",
+    "static", "struct", "trait", "true", "try", "type", "typeof", "unsafe", "unsized", "use",
+    "virtual", "where", "while", "yield",
+];
+
+/// Convert a flag key, e.g. `new-checkout` or `newCheckout`, to a snake case identifier.
+fn identifier(key: &str) -> Option<String> {
+    let mut name = String::new();
+    let mut previous: Option<char> = None;
+
+    for c in key.chars() {
+        if c.is_ascii_alphanumeric() {
+            // INFO: Split camel case words, e.g. `newCheckout` into `new_checkout`
+            if c.is_ascii_uppercase()
+                && previous.is_some_and(|previous| {
+                    previous.is_ascii_lowercase() || previous.is_ascii_digit()
+                })
+            {
+                name.push('_');
+            }
+            name.push(c.to_ascii_lowercase());
+        } else if !name.is_empty() && !name.ends_with('_') {
+            name.push('_');
+        }
+        previous = Some(c);
+    }
+
+    let name = name.trim_end_matches('_');
+    if name.is_empty() {
+        return None;
+    }
+
+    Some(if name.starts_with(|c: char| c.is_ascii_digit()) {
+        format!("flag_{name}")
+    } else if matches!(name, "crate" | "self" | "super") {
+        format!("{name}_flag")
+    } else if KEYWORDS.contains(&name) {
+        format!("r#{name}")
+    } else {
+        name.to_string()
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn generate_accessors() {
+        let module = generate(
+            r#"{
+                "flags": [
+                    {
+                        "key": "new-checkout",
+                        "type": "bool",
+                        "default": false,
+                        "description": "Serve the new checkout flow.\n\nRolled out per region."
+                    },
+                    { "key": "maxItems", "type": "int", "default": 10 },
+                    { "key": "discount.rate", "type": "float", "default": 1 },
+                    {
+                        "key": "checkout-title",
+                        "type": "string",
+                        "default": "Check \"out\"",
+                        "deprecated": "Use the new checkout."
+                    },
+                    { "key": "type", "type": "string", "default": "", "deprecated": true }
+                ]
+            }"#,
+        )
+        .unwrap();
+
+        assert!(module.contains(
+            "    /// Serve the new checkout flow.\n    ///\n    /// Rolled out per region.\n    ///\n"
+        ));
+        assert!(module.contains(
+            "pub const NEW_CHECKOUT: ::open_feature::Flag<bool> =\n        \
+             ::open_feature::Flag::new(\"new-checkout\", false);"
+        ));
+        assert!(module.contains("pub async fn new_checkout("));
+        assert!(module.contains(") -> bool {"));
+
+        assert!(module.contains("::open_feature::Flag::new(\"maxItems\", 10);"));
+        assert!(module.contains("pub async fn max_items("));
+
+        assert!(module.contains(
+            "pub const DISCOUNT_RATE: ::open_feature::Flag<f64> =\n        \
+             ::open_feature::Flag::new(\"discount.rate\", 1.0);"
+        ));
+
+        assert!(module.contains(
+            "    #[deprecated(note = \"Use the new checkout.\")]\n    \
+             pub const CHECKOUT_TITLE: ::open_feature::Flag<&'static str> =\n        \
+             ::open_feature::Flag::new(\"checkout-title\", \"Check \\\"out\\\"\");"
+        ));
+        assert!(module.contains(") -> ::std::string::String {"));
+
+        assert!(module.contains("    #[deprecated]\n    pub const TYPE:"));
+        assert!(module.contains("pub async fn r#type("));
+    }
+
+    #[test]
+    fn identifiers() {
+        assert_eq!(identifier("new-checkout").as_deref(), Some("new_checkout"));
+        assert_eq!(
+            identifier("newCheckoutV2").as_deref(),
+            Some("new_checkout_v2")
+        );
+        assert_eq!(identifier("HTTP_timeout").as_deref(), Some("http_timeout"));
+        assert_eq!(identifier("--beta--").as_deref(), Some("beta"));
+        assert_eq!(identifier("2fa").as_deref(), Some("flag_2fa"));
+        assert_eq!(identifier("self").as_deref(), Some("self_flag"));
+        assert_eq!(identifier("match").as_deref(), Some("r#match"));
+        assert_eq!(identifier("--"), None);
+    }
+
+    #[test]
+    fn invalid_manifests() {
+        let error = |manifest: &str| generate(manifest).unwrap_err().to_string();
+
+        assert!(matches!(generate("{"), Err(Error::Json(_))));
+        assert_eq!(
+            error(r#"{ "flag": [] }"#),
+            "Invalid manifest: expected a \"flags\" array"
+        );
+        assert_eq!(
+            error(r#"{ "flags": [{ "key": "a", "type": "bool", "default": 1 }] }"#),
+            "Invalid manifest: flag \"a\" has a default value that is not a bool"
+        );
+        assert_eq!(
+            error(r#"{ "flags": [{ "key": "a", "type": "object", "default": {} }] }"#),
+            "Invalid manifest: flag \"a\" has an unsupported type \"object\", \
+             expected bool, int, float or string"
+        );
+        assert_eq!(
+            error(
+                r#"{ "flags": [
+                    { "key": "new-checkout", "type": "bool", "default": true },
+                    { "key": "newCheckout", "type": "bool", "default": true }
+                ] }"#
+            ),
+            "Invalid manifest: flag \"newCheckout\" has the same accessor name as another flag: \
+             new_checkout"
+        );
+    }
+}
@@ -0,0 +1,60 @@
+// @generated by open-feature-codegen from the flag manifest, do not edit.
+
+/// The typed flags of the flag manifest.
+#[allow(dead_code)]
+pub mod flags {
+    /// Serve the new checkout flow.
+    ///
+    /// Flag key: `new-checkout`.
+    pub const NEW_CHECKOUT: ::open_feature::Flag<bool> =
+        ::open_feature::Flag::new("new-checkout", false);
+
+    /// Serve the new checkout flow.
+    ///
+    /// Flag key: `new-checkout`.
+    pub async fn new_checkout(
+        client: &::open_feature::Client,
+        evaluation_context: &::open_feature::EvaluationContext,
+    ) -> bool {
+        client.get(&NEW_CHECKOUT, Some(evaluation_context)).await
+    }
+
+    /// Flag key: `maxItems`.
+    pub const MAX_ITEMS: ::open_feature::Flag<i64> =
+        ::open_feature::Flag::new("maxItems", 10);
+
+    /// Flag key: `maxItems`.
+    pub async fn max_items(
+        client: &::open_feature::Client,
+        evaluation_context: &::open_feature::EvaluationContext,
+    ) -> i64 {
+        client.get(&MAX_ITEMS, Some(evaluation_context)).await
+    }
+
+    /// Flag key: `discount.rate`.
+    pub const DISCOUNT_RATE: ::open_feature::Flag<f64> =
+        ::open_feature::Flag::new("discount.rate", 0.5);
+
+    /// Flag key: `discount.rate`.
+    pub async fn discount_rate(
+        client: &::open_feature::Client,
+        evaluation_context: &::open_feature::EvaluationContext,
+    ) -> f64 {
+        client.get(&DISCOUNT_RATE, Some(evaluation_context)).await
+    }
+
+    /// Flag key: `checkout-title`.
+    #[deprecated(note = "Use the title of the new checkout flow.")]
+    pub const CHECKOUT_TITLE: ::open_feature::Flag<&'static str> =
+        ::open_feature::Flag::new("checkout-title", "Checkout");
+
+    /// Flag key: `checkout-title`.
+    #[deprecated(note = "Use the title of the new checkout flow.")]
+    #[allow(deprecated)]
+    pub async fn checkout_title(
+        client: &::open_feature::Client,
+        evaluation_context: &::open_feature::EvaluationContext,
+    ) -> ::std::string::String {
+        client.get(&CHECKOUT_TITLE, Some(evaluation_context)).await
+    }
+}
@@ -0,0 +1,75 @@
+//! Compile the module generated from `fixtures/flags.json` against the SDK.
+
+use std::collections::HashMap;
+
+use open_feature::{
+    provider::{ProviderMetadata, ResolutionDetails, ValueProvider},
+    EvaluationContext, EvaluationError, EvaluationErrorCode, EvaluationResult, OpenFeature, Type,
+    Value,
+};
+
+include!("fixtures/flags.rs");
+
+struct MapProvider {
+    metadata: ProviderMetadata,
+    values: HashMap<&'static str, Value>,
+}
+
+#[open_feature::async_trait]
+impl ValueProvider for MapProvider {
+    fn metadata(&self) -> &ProviderMetadata {
+        &self.metadata
+    }
+
+    async fn resolve_value(
+        &self,
+        flag_key: &str,
+        _expected: Type,
+        _evaluation_context: &EvaluationContext,
+    ) -> EvaluationResult<ResolutionDetails<Value>> {
+        self.values
+            .get(flag_key)
+            .map(|value| ResolutionDetails::new(value.clone()))
+            .ok_or_else(|| {
+                EvaluationError::builder()
+                    .code(EvaluationErrorCode::FlagNotFound)
+                    .build()
+            })
+    }
+}
+
+#[test]
+fn fixture_is_up_to_date() {
+    let module = open_feature_codegen::generate(include_str!("fixtures/flags.json")).unwrap();
+
+    assert_eq!(module, include_str!("fixtures/flags.rs"));
+}
+
+#[tokio::test]
+#[allow(deprecated)]
+async fn typed_accessors() {
+    let api = OpenFeature::builder()
+        .provider(MapProvider {
+            metadata: ProviderMetadata::new("Map"),
+            values: HashMap::from([
+                ("new-checkout", Value::Bool(true)),
+                ("maxItems", Value::Int(25)),
+                ("checkout-title", Value::String("Pay".to_string())),
+            ]),
+        })
+        .build()
+        .await;
+    let client = api.create_client();
+    let context = EvaluationContext::default();
+
+    let new_checkout: bool = flags::new_checkout(&client, &context).await;
+    let max_items: i64 = flags::max_items(&client, &context).await;
+    let discount_rate: f64 = flags::discount_rate(&client, &context).await;
+    let checkout_title: String = flags::checkout_title(&client, &context).await;
+
+    assert!(new_checkout);
+    assert_eq!(max_items, 25);
+    assert_eq!(discount_rate.to_string(), "0.5");
+    assert_eq!(checkout_title, "Pay");
+    assert_eq!(flags::NEW_CHECKOUT.key(), "new-checkout");
+}
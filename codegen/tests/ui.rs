@@ -0,0 +1,7 @@
+//! Check the diagnostics of the generated module where a flag is deprecated or removed.
+
+#[test]
+fn diagnostics() {
+    let t = trybuild::TestCases::new();
+    t.compile_fail("tests/ui/*.rs");
+}
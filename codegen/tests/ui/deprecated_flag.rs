@@ -0,0 +1,11 @@
+#![deny(deprecated)]
+
+include!("../fixtures/flags.rs");
+
+async fn title(client: &open_feature::Client) -> String {
+    flags::checkout_title(client, &open_feature::EvaluationContext::default()).await
+}
+
+fn main() {
+    let _ = flags::CHECKOUT_TITLE.key();
+}
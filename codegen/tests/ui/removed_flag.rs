@@ -0,0 +1,9 @@
+include!("../fixtures/flags.rs");
+
+async fn legacy_banner(client: &open_feature::Client) -> bool {
+    flags::legacy_banner(client, &open_feature::EvaluationContext::default()).await
+}
+
+fn main() {
+    let _ = flags::LEGACY_BANNER.key();
+}
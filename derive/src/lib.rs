@@ -0,0 +1,374 @@
+//! Derive macros for the OpenFeature Rust SDK, re-exported by `open-feature` behind the
+//! `derive` feature.
+
+use darling::{ast::Data, util::Override, Error, FromDeriveInput, FromField};
+use proc_macro::TokenStream;
+use proc_macro2::TokenStream as TokenStream2;
+use quote::quote;
+use syn::{parse_macro_input, parse_quote, Attribute, DeriveInput, Generics, Ident, Path, Type};
+
+// ============================================================
+//  FlagStruct
+// ============================================================
+
+#[derive(FromDeriveInput)]
+#[darling(attributes(flag), supports(struct_named))]
+struct FlagStruct {
+    ident: Ident,
+    generics: Generics,
+    data: Data<(), FlagStructField>,
+}
+
+#[derive(FromField)]
+#[darling(attributes(flag))]
+struct FlagStructField {
+    ident: Option<Ident>,
+    ty: Type,
+    #[darling(default)]
+    rename: Option<String>,
+    #[darling(default)]
+    default: Option<Override<Path>>,
+}
+
+/// Derive `TryFrom<StructValue>` and `Into<Value>` for a struct with named fields, so it is
+/// evaluated with `Client::get_struct_value`.
+///
+/// Fields are converted through the `StructField` trait: primitive types, `Vec`, `Option` and
+/// other `FlagStruct` structs. A missing `Option` field is `None`, and a `None` field is omitted.
+/// The elements of a `Vec` cannot be `Option`s, as an array cannot hold a missing element.
+///
+/// Field attributes:
+/// - `#[flag(rename = "name")]` reads and writes the field under another key.
+/// - `#[flag(default)]` uses `Default::default()` when the field is missing.
+/// - `#[flag(default = "path::to::function")]` calls the function when the field is missing.
+#[proc_macro_derive(FlagStruct, attributes(flag))]
+pub fn derive_flag_struct(input: TokenStream) -> TokenStream {
+    let input = parse_macro_input!(input as DeriveInput);
+
+    match FlagStruct::from_derive_input(&input) {
+        Ok(input) => expand_flag_struct(input).into(),
+        Err(error) => error.write_errors().into(),
+    }
+}
+
+fn expand_flag_struct(input: FlagStruct) -> TokenStream2 {
+    let FlagStruct {
+        ident,
+        mut generics,
+        data,
+    } = input;
+    let fields = data
+        .take_struct()
+        .expect("darling only accepts structs with named fields")
+        .fields;
+
+    bound_type_params(&mut generics, &parse_quote!(::open_feature::StructField));
+    let (impl_generics, ty_generics, where_clause) = generics.split_for_impl();
+
+    let idents = fields
+        .iter()
+        .map(|field| field.ident.as_ref().expect("named fields have an ident"))
+        .collect::<Vec<_>>();
+    let keys = fields
+        .iter()
+        .zip(&idents)
+        .map(|(field, ident)| {
+            field
+                .rename
+                .clone()
+                .unwrap_or_else(|| ident.to_string().trim_start_matches("r#").to_string())
+        })
+        .collect::<Vec<_>>();
+    let conversions = fields.iter().zip(&keys).map(|(field, key)| {
+        let ty = &field.ty;
+        let convert = quote! {
+            <#ty as ::open_feature::StructField>::from_field(value)
+                .map_err(|error| error.in_field(#key))?
+        };
+
+        match &field.default {
+            None => quote! {{
+                let value = fields.remove(#key);
+                #convert
+            }},
+            Some(default) => {
+                let default = match default {
+                    Override::Inherit => quote!(::std::default::Default::default()),
+                    Override::Explicit(path) => quote!(#path()),
+                };
+
+                quote! {
+                    match fields.remove(#key) {
+                        ::std::option::Option::None => #default,
+                        value => #convert,
+                    }
+                }
+            }
+        }
+    });
+
+    quote! {
+        impl #impl_generics ::std::convert::TryFrom<::open_feature::StructValue>
+            for #ident #ty_generics #where_clause
+        {
+            type Error = ::open_feature::StructFieldError;
+
+            fn try_from(
+                value: ::open_feature::StructValue,
+            ) -> ::std::result::Result<Self, Self::Error> {
+                let mut fields = value.fields;
+
+                ::std::result::Result::Ok(Self {
+                    #(#idents: #conversions,)*
+                })
+            }
+        }
+
+        impl #impl_generics ::std::convert::From<#ident #ty_generics>
+            for ::open_feature::StructValue #where_clause
+        {
+            fn from(value: #ident #ty_generics) -> Self {
+                let mut fields = ::std::collections::HashMap::new();
+                #(
+                    if let ::std::option::Option::Some(field) =
+                        ::open_feature::StructField::into_field(value.#idents)
+                    {
+                        fields.insert(::std::string::String::from(#keys), field);
+                    }
+                )*
+
+                ::open_feature::StructValue { fields }
+            }
+        }
+
+        impl #impl_generics ::std::convert::From<#ident #ty_generics>
+            for ::open_feature::Value #where_clause
+        {
+            fn from(value: #ident #ty_generics) -> Self {
+                ::open_feature::Value::Struct(value.into())
+            }
+        }
+
+        impl #impl_generics ::open_feature::StructField for #ident #ty_generics #where_clause {
+            fn from_field(
+                value: ::std::option::Option<::open_feature::Value>,
+            ) -> ::std::result::Result<Self, ::open_feature::StructFieldError> {
+                match value {
+                    ::std::option::Option::Some(::open_feature::Value::Struct(value)) => {
+                        Self::try_from(value)
+                    }
+                    ::std::option::Option::Some(value) => {
+                        ::std::result::Result::Err(::open_feature::StructFieldError::type_mismatch(
+                            &::open_feature::Type::Struct,
+                            &value,
+                        ))
+                    }
+                    ::std::option::Option::None => {
+                        ::std::result::Result::Err(::open_feature::StructFieldError::missing())
+                    }
+                }
+            }
+
+            fn into_field(self) -> ::std::option::Option<::open_feature::Value> {
+                ::std::option::Option::Some(self.into())
+            }
+        }
+
+        impl #impl_generics ::open_feature::StructElement for #ident #ty_generics #where_clause {
+            fn into_element(self) -> ::open_feature::Value {
+                self.into()
+            }
+        }
+    }
+}
+
+// ============================================================
+//  IntoEvaluationContext
+// ============================================================
+
+#[derive(FromDeriveInput)]
+#[darling(attributes(context), supports(struct_named))]
+struct IntoEvaluationContext {
+    ident: Ident,
+    generics: Generics,
+    data: Data<(), ContextField>,
+}
+
+#[derive(FromField)]
+#[darling(attributes(context), forward_attrs(targeting_key))]
+struct ContextField {
+    ident: Option<Ident>,
+    ty: Type,
+    attrs: Vec<Attribute>,
+    #[darling(default)]
+    rename: Option<String>,
+    #[darling(default)]
+    skip: bool,
+    #[darling(default)]
+    private: bool,
+}
+
+/// Derive `From<&T>` and `From<T>` for `EvaluationContext` for a struct with named fields.
+///
+/// Fields are added as custom fields through the `ContextField` trait: the types of
+/// `EvaluationContextFieldValue`, including `OffsetDateTime` as a date-time, and `Option`s of
+/// them, absent when `None`. The fields of nested `IntoEvaluationContext` structs are flattened
+/// under the key of the struct, e.g. `tenant.plan`.
+///
+/// Field attributes:
+/// - `#[targeting_key]` sets the targeting key to the field, converted with `ToString`. In a
+///   nested struct, it is added as a string field instead.
+/// - `#[context(rename = "name")]` adds the field under another key.
+/// - `#[context(skip)]` leaves the field out of the context.
+/// - `#[context(private)]` marks the field as private, redacted outside of flag resolution.
+#[proc_macro_derive(IntoEvaluationContext, attributes(context, targeting_key))]
+pub fn derive_into_evaluation_context(input: TokenStream) -> TokenStream {
+    let input = parse_macro_input!(input as DeriveInput);
+
+    match IntoEvaluationContext::from_derive_input(&input).and_then(expand_into_evaluation_context)
+    {
+        Ok(output) => output.into(),
+        Err(error) => error.write_errors().into(),
+    }
+}
+
+fn expand_into_evaluation_context(input: IntoEvaluationContext) -> Result<TokenStream2, Error> {
+    let IntoEvaluationContext {
+        ident,
+        mut generics,
+        data,
+    } = input;
+    let fields = data
+        .take_struct()
+        .expect("darling only accepts structs with named fields")
+        .fields;
+
+    bound_type_params(&mut generics, &parse_quote!(::open_feature::ContextField));
+    let (impl_generics, ty_generics, where_clause) = generics.split_for_impl();
+
+    let mut targeting_key = None;
+    let mut custom_fields = Vec::new();
+    let mut nested_fields = Vec::new();
+
+    for field in fields.iter().filter(|field| !field.skip) {
+        let ident = field.ident.as_ref().expect("named fields have an ident");
+        let key = field
+            .rename
+            .clone()
+            .unwrap_or_else(|| ident.to_string().trim_start_matches("r#").to_string());
+        let private = field.private;
+
+        let nested_value = if let Some(attr) = field
+            .attrs
+            .iter()
+            .find(|attr| attr.path().is_ident("targeting_key"))
+        {
+            if targeting_key.is_some() {
+                return Err(
+                    Error::custom("Only one field can be the targeting key").with_span(attr.path())
+                );
+            }
+
+            // INFO: An optional targeting key is left unset when `None`
+            let key_value = if is_option(&field.ty) {
+                quote! {
+                    value.#ident.as_ref().map(::std::string::ToString::to_string)
+                }
+            } else {
+                quote! {
+                    ::std::option::Option::Some(::std::string::ToString::to_string(&value.#ident))
+                }
+            };
+            targeting_key = Some(key_value.clone());
+
+            // INFO: Nested, the targeting key is a string field, whatever the type of the key
+            quote!(&#key_value)
+        } else {
+            custom_fields.push(quote! {
+                ::open_feature::ContextField::add_to_context(
+                    &value.#ident,
+                    &mut context,
+                    #key,
+                    #private,
+                );
+            });
+
+            quote!(&value.#ident)
+        };
+
+        nested_fields.push(quote! {
+            ::open_feature::ContextField::add_to_context(
+                #nested_value,
+                context,
+                &::std::format!("{}.{}", key, #key),
+                private || #private,
+            );
+        });
+    }
+
+    let targeting_key = targeting_key.unwrap_or_else(|| quote!(::std::option::Option::None));
+
+    Ok(quote! {
+        impl #impl_generics ::std::convert::From<&#ident #ty_generics>
+            for ::open_feature::EvaluationContext #where_clause
+        {
+            fn from(value: &#ident #ty_generics) -> Self {
+                let mut context = ::open_feature::EvaluationContext::default();
+                context.targeting_key = #targeting_key;
+                #(#custom_fields)*
+
+                context
+            }
+        }
+
+        impl #impl_generics ::std::convert::From<#ident #ty_generics>
+            for ::open_feature::EvaluationContext #where_clause
+        {
+            fn from(value: #ident #ty_generics) -> Self {
+                Self::from(&value)
+            }
+        }
+
+        impl #impl_generics ::open_feature::ContextField for #ident #ty_generics #where_clause {
+            fn add_to_context(
+                &self,
+                context: &mut ::open_feature::EvaluationContext,
+                key: &str,
+                private: bool,
+            ) {
+                let value = self;
+                #(#nested_fields)*
+            }
+        }
+    })
+}
+
+// ============================================================
+//  Helpers
+// ============================================================
+
+/// Bound the type parameters of `generics` by `bound`, rather than the field types which may be
+/// recursive.
+fn bound_type_params(generics: &mut Generics, bound: &Path) {
+    let params = generics
+        .type_params()
+        .map(|param| param.ident.clone())
+        .collect::<Vec<_>>();
+    let where_clause = generics.make_where_clause();
+
+    for param in params {
+        where_clause.predicates.push(parse_quote!(#param: #bound));
+    }
+}
+
+/// Return `true` if `ty` is spelled as an [`Option`].
+fn is_option(ty: &Type) -> bool {
+    match ty {
+        Type::Path(ty) => ty
+            .path
+            .segments
+            .last()
+            .is_some_and(|segment| segment.ident == "Option"),
+        _ => false,
+    }
+}
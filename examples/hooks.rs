@@ -1,7 +1,7 @@
 use open_feature::{
-    provider::{FeatureProvider, ProviderMetadata, ProviderStatus, ResolutionDetails},
+    provider::{ProviderMetadata, ProviderStatus, ResolutionDetails, ValueProvider},
     EvaluationContext, EvaluationDetails, EvaluationError, EvaluationOptions, EvaluationResult,
-    Hook, HookContext, HookHints, OpenFeature, StructValue, Value,
+    Hook, HookContext, HookHints, OpenFeature, Type, Value,
 };
 
 struct DummyProvider(ProviderMetadata);
@@ -13,7 +13,7 @@ impl Default for DummyProvider {
 }
 
 #[async_trait::async_trait]
-impl FeatureProvider for DummyProvider {
+impl ValueProvider for DummyProvider {
     fn metadata(&self) -> &ProviderMetadata {
         &self.0
     }
@@ -22,45 +22,14 @@ impl FeatureProvider for DummyProvider {
         ProviderStatus::Ready
     }
 
-    async fn resolve_bool_value(
+    async fn resolve_value(
         &self,
         _flag_key: &str,
+        _expected: Type,
         _evaluation_context: &EvaluationContext,
-    ) -> EvaluationResult<ResolutionDetails<bool>> {
+    ) -> EvaluationResult<ResolutionDetails<Value>> {
         Ok(ResolutionDetails::new(true))
     }
-
-    async fn resolve_int_value(
-        &self,
-        _flag_key: &str,
-        _evaluation_context: &EvaluationContext,
-    ) -> EvaluationResult<ResolutionDetails<i64>> {
-        unimplemented!()
-    }
-
-    async fn resolve_float_value(
-        &self,
-        _flag_key: &str,
-        _evaluation_context: &EvaluationContext,
-    ) -> EvaluationResult<ResolutionDetails<f64>> {
-        unimplemented!()
-    }
-
-    async fn resolve_string_value(
-        &self,
-        _flag_key: &str,
-        _evaluation_context: &EvaluationContext,
-    ) -> EvaluationResult<ResolutionDetails<String>> {
-        unimplemented!()
-    }
-
-    async fn resolve_struct_value(
-        &self,
-        _flag_key: &str,
-        _evaluation_context: &EvaluationContext,
-    ) -> Result<ResolutionDetails<StructValue>, EvaluationError> {
-        unimplemented!()
-    }
 }
 
 struct DummyLoggingHook(String);
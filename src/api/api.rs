@@ -1,15 +1,19 @@
 use std::sync::OnceLock;
 
-use tokio::sync::{RwLock, RwLockReadGuard, RwLockWriteGuard};
+use async_lock::{RwLock, RwLockReadGuard, RwLockWriteGuard};
 
 use crate::{
-    provider::{FeatureProvider, ProviderMetadata},
-    Client, EvaluationContext, Hook, HookWrapper,
+    provider::{FeatureProvider, FlagDefinition, ProviderMetadata, ProviderStatus},
+    Client, EvaluationContext, EvaluationError, EvaluationErrorCode, EvaluationEventOptions,
+    EvaluationEventStats, EvaluationEventStream, EvaluationResult, HookWrapper,
 };
 
 use super::{
-    global_evaluation_context::GlobalEvaluationContext, global_hooks::GlobalHooks,
-    provider_registry::ProviderRegistry,
+    builder::OpenFeatureBuilder,
+    evaluation_events::EvaluationEvents,
+    global_evaluation_context::GlobalEvaluationContext,
+    global_hooks::GlobalHooks,
+    provider_registry::{PendingProvider, ProviderRegistry},
 };
 
 /// The singleton instance of [`OpenFeature`] struct.
@@ -20,17 +24,58 @@ fn get_singleton() -> &'static RwLock<OpenFeature> {
     SINGLETON.get_or_init(|| RwLock::new(OpenFeature::default()))
 }
 
+/// Serialize the tests replacing the singleton with the tests relying on its state.
+#[cfg(test)]
+pub(super) static SINGLETON_TEST_LOCK: async_lock::Mutex<()> = async_lock::Mutex::new(());
+
 /// THE struct of the OpenFeature API.
 /// Access it via [`OpenFeature::singleton()`] or [`OpenFeature::singleton_mut()`].
-#[derive(Default)]
 pub struct OpenFeature {
     evaluation_context: GlobalEvaluationContext,
     hooks: GlobalHooks,
+    evaluation_events: EvaluationEvents,
 
     provider_registry: ProviderRegistry,
 }
 
+impl Default for OpenFeature {
+    fn default() -> Self {
+        let evaluation_context = GlobalEvaluationContext::default();
+
+        // INFO: Providers are initialized with the global evaluation context
+        Self {
+            provider_registry: ProviderRegistry::new(evaluation_context.clone()),
+            evaluation_context,
+            hooks: GlobalHooks::default(),
+            evaluation_events: EvaluationEvents::default(),
+        }
+    }
+}
+
 impl OpenFeature {
+    /// Create a builder of an [`OpenFeature`] instance independent from the singleton, e.g. for
+    /// tests running in parallel.
+    pub fn builder() -> OpenFeatureBuilder {
+        OpenFeatureBuilder::default()
+    }
+
+    /// Make this instance the singleton returned by [`OpenFeature::singleton()`], replacing the
+    /// current one. The clients created out of the previous singleton keep using its providers.
+    pub async fn install_global(self) {
+        *get_singleton().write().await = self;
+    }
+
+    /// Return an instance sharing the state of this one, so that it can be used once the lock
+    /// guarding this one is released.
+    pub(super) fn shared(&self) -> Self {
+        Self {
+            evaluation_context: self.evaluation_context.clone(),
+            hooks: self.hooks.clone(),
+            evaluation_events: self.evaluation_events.clone(),
+            provider_registry: self.provider_registry.clone(),
+        }
+    }
+
     /// Get the singleton of [`OpenFeature`].
     pub async fn singleton() -> RwLockReadGuard<'static, Self> {
         get_singleton().read().await
@@ -42,11 +87,19 @@ impl OpenFeature {
     }
 
     /// Set the global evaluation context.
+    ///
+    /// This is also the static context of the static context paradigm: every registered provider
+    /// is handed the previous and the new context through
+    /// [`FeatureProvider::on_context_changed`], and is reported as
+    /// [`ProviderStatus::Reconciling`] until it returns.
     pub async fn set_evaluation_context(&mut self, evaluation_context: EvaluationContext) {
-        let mut context = self.evaluation_context.get_mut().await;
+        let old_context = self.evaluation_context.replace(evaluation_context.clone());
 
-        context.targeting_key = evaluation_context.targeting_key;
-        context.custom_fields = evaluation_context.custom_fields;
+        self.provider_registry
+            .reconcile(&old_context, &evaluation_context)
+            .await;
+
+        self.evaluation_context.notify();
     }
 
     /// Set the default provider.
@@ -59,36 +112,88 @@ impl OpenFeature {
         self.provider_registry.set_named(name, provider).await;
     }
 
+    /// Bind the already type-erased `provider` to the corresponding `name`.
+    pub(super) async fn bind_provider(&mut self, name: &str, provider: PendingProvider) {
+        self.provider_registry.bind(name, provider).await;
+    }
+
     /// Add a new hook to the global list of hooks.
-    pub async fn add_hook<T: Hook>(&mut self, hook: T) {
-        let mut lock = self.hooks.get_mut().await;
-        lock.push(HookWrapper::new(hook));
+    /// Pass a [`HookWrapper`] to configure how the hook is executed, e.g. with a timeout.
+    #[allow(clippy::unused_async)] // INFO: Kept async not to break callers
+    pub async fn add_hook<T: Into<HookWrapper>>(&mut self, hook: T) {
+        self.hooks.push(&hook.into());
     }
 
     /// Return the metadata of default (unnamed) provider.
+    #[allow(clippy::unused_async)] // INFO: Kept async not to break callers
     pub async fn provider_metadata(&self) -> ProviderMetadata {
         self.provider_registry
             .get_default()
-            .await
             .get()
             .metadata()
             .clone()
     }
 
     /// Return the metadata of named provider (a provider bound to clients with this name).
+    #[allow(clippy::unused_async)] // INFO: Kept async not to break callers
     pub async fn named_provider_metadata(&self, name: &str) -> Option<ProviderMetadata> {
         self.provider_registry
             .get_named(name)
-            .await
             .map(|provider| provider.get().metadata().clone())
     }
 
+    /// Return the status of the provider bound to `domain`, or of the default provider if none
+    /// is.
+    #[allow(clippy::unused_async)] // INFO: Kept async not to break callers
+    pub async fn provider_status(&self, domain: &str) -> ProviderStatus {
+        self.provider_registry.get(domain).status()
+    }
+
+    /// List the flags of the provider bound to `domain`, or of the default provider if none is.
+    ///
+    /// An error is returned if the provider does not support listing its flags, or if it fails.
+    pub async fn list_flags(&self, domain: &str) -> EvaluationResult<Vec<FlagDefinition>> {
+        let provider = self.provider_registry.get(domain).get();
+
+        provider.list_flags().await.unwrap_or_else(|| {
+            Err(EvaluationError::builder()
+                .code(EvaluationErrorCode::General(
+                    "Flag listing not supported".to_string(),
+                ))
+                .message(format!(
+                    "Provider {} cannot list its flags",
+                    provider.metadata().name
+                ))
+                .build())
+        })
+    }
+
+    /// Subscribe to the completed evaluations of all the clients, e.g. for exposure logging.
+    ///
+    /// Evaluations are only published while at least one subscriber is listening.
+    pub fn subscribe_evaluation_events(&self) -> EvaluationEventStream {
+        self.evaluation_events.subscribe()
+    }
+
+    /// Configure the evaluation event stream.
+    /// The streams of the existing subscribers end, as they are bound to the previous
+    /// configuration.
+    pub fn set_evaluation_event_options(&mut self, options: EvaluationEventOptions) {
+        self.evaluation_events.configure(options);
+    }
+
+    /// Return the counters of the evaluation event stream.
+    pub fn evaluation_event_stats(&self) -> EvaluationEventStats {
+        self.evaluation_events.stats()
+    }
+
     /// Create a new client with default name.
     pub fn create_client(&self) -> Client {
         Client::new(
             String::default(),
             self.evaluation_context.clone(),
             self.hooks.clone(),
+            self.evaluation_events.clone(),
             self.provider_registry.clone(),
         )
     }
@@ -100,11 +205,12 @@ impl OpenFeature {
             name.to_string(),
             self.evaluation_context.clone(),
             self.hooks.clone(),
+            self.evaluation_events.clone(),
             self.provider_registry.clone(),
         )
     }
 
-    /// Drops all the registered providers.
+    /// Shut down and unbind all the registered providers, leaving the no-op provider as default.
     pub async fn shutdown(&mut self) {
         self.provider_registry.clear().await;
     }
@@ -112,12 +218,15 @@ impl OpenFeature {
 
 #[cfg(test)]
 mod tests {
-    use std::sync::Arc;
+    use std::sync::{
+        atomic::{AtomicBool, Ordering},
+        Arc, Mutex,
+    };
 
     use super::*;
     use crate::{
-        provider::{MockFeatureProvider, NoOpProvider, ResolutionDetails},
-        EvaluationContextFieldValue,
+        provider::{MockFeatureProvider, NoOpProvider, ResolutionDetails, ValueProvider},
+        EvaluationContextFieldValue, EvaluationResult, FlagMetadata, FnHook, Type, Value,
     };
     use mockall::predicate;
     use spec::spec;
@@ -128,6 +237,8 @@ mod tests {
     )]
     #[tokio::test]
     async fn singleton_multi_thread() {
+        let _lock = SINGLETON_TEST_LOCK.lock().await;
+
         let reader1 = tokio::spawn(async move {
             let _ = OpenFeature::singleton().await.provider_metadata();
         });
@@ -155,6 +266,35 @@ mod tests {
         );
     }
 
+    #[test]
+    fn run_without_tokio() {
+        futures_executor::block_on(async {
+            let mut provider = MockFeatureProvider::new();
+            provider.expect_initialize().returning(|_| {});
+            provider.expect_on_context_changed().return_const(());
+            provider.expect_hooks().return_const(vec![]);
+            provider
+                .expect_metadata()
+                .return_const(ProviderMetadata::default());
+            provider
+                .expect_resolve_int_value()
+                .return_const(Ok(ResolutionDetails::new(200)));
+
+            let mut api = OpenFeature::default();
+            api.set_provider(provider).await;
+            api.set_evaluation_context(EvaluationContext::default().with_targeting_key("user-1"))
+                .await;
+
+            assert_eq!(
+                api.create_client()
+                    .get_int_value("some-key", None, None)
+                    .await
+                    .unwrap(),
+                200
+            );
+        });
+    }
+
     #[spec(
         number = "1.1.2.1",
         text = "The API MUST define a provider mutator, a function to set the default provider, which accepts an API-conformant provider implementation."
@@ -203,7 +343,7 @@ mod tests {
         text = "The provider mutator function MUST invoke the shutdown function on the previously registered provider once it's no longer being used to resolve flag values."
     )]
     #[test]
-    fn invoke_shutdown_on_old_provider_checked_by_type_system() {}
+    fn invoke_shutdown_on_old_provider_checked_by_registry() {}
 
     #[spec(
         number = "1.1.3",
@@ -328,6 +468,17 @@ mod tests {
         api.set_provider(NoOpProvider::default()).await;
 
         api.shutdown().await;
+
+        // The no-op provider is left in place.
+        assert_eq!(api.provider_metadata().await.name, "No-op Provider");
+        assert_eq!(
+            api.create_client()
+                .get_bool_value("flag", None, None)
+                .await
+                .unwrap_err()
+                .code,
+            EvaluationErrorCode::ProviderNotReady
+        );
     }
 
     #[spec(
@@ -384,6 +535,8 @@ mod tests {
             )
             .return_const(Ok(ResolutionDetails::new(300)));
 
+        provider.expect_on_context_changed().return_const(());
+
         // Register the provider.
         let mut api = OpenFeature::default();
         api.set_provider(provider).await;
@@ -423,17 +576,139 @@ mod tests {
     }
 
     #[spec(
-        number = "3.2.2.1",
-        text = "The API MUST have a method for setting the global evaluation context."
+        number = "2.6.1",
+        text = "The provider MAY define an on context changed function, which takes an argument for the previous context and the newly set context, in order to respond to an evaluation context change."
     )]
     #[spec(
-        number = "3.2.2.2",
-        text = "The Client and invocation MUST NOT have a method for supplying evaluation context."
+        number = "3.2.2.1",
+        text = "The API MUST have a method for setting the global evaluation context."
     )]
     #[spec(
         number = "3.2.4.1",
         text = "When the global evaluation context is set, the on context changed handler MUST run."
     )]
+    #[tokio::test]
+    async fn set_evaluation_context_reconciles_providers() {
+        let client = Arc::new(OnceLock::<Client>::new());
+        let status_while_reconciling = Arc::new(Mutex::new(None));
+
+        let mut provider = MockFeatureProvider::new();
+        provider.expect_initialize().returning(|_| {});
+        provider.expect_status().return_const(ProviderStatus::Ready);
+        provider
+            .expect_on_context_changed()
+            .with(
+                predicate::eq(EvaluationContext::default()),
+                predicate::eq(EvaluationContext::default().with_targeting_key("user-1")),
+            )
+            .once()
+            .returning({
+                let client = client.clone();
+                let status_while_reconciling = status_while_reconciling.clone();
+                move |_, _| {
+                    *status_while_reconciling.lock().unwrap() =
+                        Some(client.get().unwrap().provider_status());
+                }
+            });
+
+        let mut api = OpenFeature::default();
+        api.set_provider(provider).await;
+        let _ = client.set(api.create_client());
+
+        api.set_evaluation_context(EvaluationContext::default().with_targeting_key("user-1"))
+            .await;
+
+        assert_eq!(
+            *status_while_reconciling.lock().unwrap(),
+            Some(ProviderStatus::Reconciling)
+        );
+        assert_eq!(api.provider_status("").await, ProviderStatus::Ready);
+    }
+
+    /// A provider resolving every flag to its own name.
+    struct NamedProvider(ProviderMetadata);
+
+    #[async_trait::async_trait]
+    impl ValueProvider for NamedProvider {
+        fn metadata(&self) -> &ProviderMetadata {
+            &self.0
+        }
+
+        async fn resolve_value(
+            &self,
+            _flag_key: &str,
+            _expected: Type,
+            _evaluation_context: &EvaluationContext,
+        ) -> EvaluationResult<ResolutionDetails<Value>> {
+            // INFO: Leave room for a swap in the middle of the evaluation
+            tokio::task::yield_now().await;
+            Ok(ResolutionDetails::new(self.0.name.clone()))
+        }
+    }
+
+    #[tokio::test(flavor = "multi_thread", worker_threads = 4)]
+    async fn swap_provider_during_evaluations() {
+        let evaluations = Arc::new(Mutex::new(Vec::new()));
+
+        let mut api = OpenFeature::default();
+        api.set_provider(NamedProvider(ProviderMetadata::new("Old")))
+            .await;
+        let client = api.create_client().with_hook(FnHook::finally_fn({
+            let evaluations = evaluations.clone();
+            move |context, details, _| {
+                evaluations.lock().unwrap().push((
+                    context.provider_metadata.name.clone(),
+                    details.value.as_str().map(ToString::to_string),
+                ));
+            }
+        }));
+
+        let swapping = Arc::new(AtomicBool::new(true));
+        let tasks: Vec<_> = (0..4)
+            .map(|_| {
+                let client = client.clone();
+                let swapping = swapping.clone();
+                tokio::spawn(async move {
+                    while swapping.load(Ordering::Relaxed) {
+                        let value = client.get_string_value("flag", None, None).await.unwrap();
+                        assert!(value == "Old" || value == "New", "{value}");
+                    }
+                })
+            })
+            .collect();
+
+        for name in ["New", "Old"].repeat(10) {
+            api.set_provider(NamedProvider(ProviderMetadata::new(name)))
+                .await;
+
+            // INFO: Let a few evaluations go through before the next swap
+            let evaluated = evaluations.lock().unwrap().len();
+            while evaluations.lock().unwrap().len() < evaluated + 8 {
+                tokio::task::yield_now().await;
+            }
+        }
+        swapping.store(false, Ordering::Relaxed);
+
+        for task in tasks {
+            task.await.unwrap();
+        }
+
+        // The hooks see the provider that resolved the flag, never one swapped in meanwhile.
+        let evaluations = evaluations.lock().unwrap();
+        for (provider_name, value) in evaluations.iter() {
+            assert_eq!(value.as_deref(), Some(provider_name.as_str()));
+        }
+        for name in ["Old", "New"] {
+            assert!(evaluations
+                .iter()
+                .any(|(provider_name, _)| provider_name == name));
+        }
+    }
+
+    #[spec(
+        number = "3.2.2.2",
+        text = "The Client and invocation MUST NOT have a method for supplying evaluation context."
+    )]
     #[test]
     fn static_context_not_applicable() {}
 
@@ -442,6 +717,8 @@ mod tests {
 
     #[tokio::test]
     async fn extended_example() {
+        let _lock = SINGLETON_TEST_LOCK.lock().await;
+
         // Acquire an OpenFeature API instance.
         let mut api = OpenFeature::singleton_mut().await;
 
@@ -484,4 +761,48 @@ mod tests {
                 .await;
         }
     }
+
+    #[tokio::test]
+    async fn list_flags() {
+        let mut api = OpenFeature::default();
+        api.set_provider(NoOpProvider::default()).await;
+
+        let mut provider = MockFeatureProvider::new();
+        provider.expect_initialize().returning(|_| {});
+        provider.expect_list_flags().returning(|| {
+            Some(Ok(vec![FlagDefinition::new("color", Type::String)
+                .with_variant("red", "#f00")
+                .with_variant("blue", "#00f")
+                .with_flag_metadata(
+                    FlagMetadata::default().with_value("owner", "web"),
+                )]))
+        });
+        api.set_named_provider("named", provider).await;
+
+        assert_eq!(api.list_flags("").await, Ok(vec![]));
+
+        let flags = api.list_flags("named").await.unwrap();
+        assert_eq!(flags.len(), 1);
+        assert_eq!(flags[0].key, "color");
+        assert_eq!(flags[0].flag_type, Type::String);
+        assert_eq!(flags[0].variants["red"], Value::String("#f00".to_string()));
+    }
+
+    #[tokio::test]
+    async fn list_flags_not_supported() {
+        let mut provider = MockFeatureProvider::new();
+        provider.expect_initialize().returning(|_| {});
+        provider.expect_list_flags().return_const(None);
+        provider
+            .expect_metadata()
+            .return_const(ProviderMetadata::new("Mock"));
+
+        let mut api = OpenFeature::default();
+        api.set_provider(provider).await;
+
+        assert!(matches!(
+            api.list_flags("unknown").await.unwrap_err().code,
+            EvaluationErrorCode::General(_)
+        ));
+    }
 }
@@ -0,0 +1,315 @@
+use std::{future::Future, sync::Arc};
+
+use tokio::runtime::{Builder, Handle, Runtime};
+
+use crate::{
+    Client, ClientMetadata, EvaluationContext, EvaluationDetails, EvaluationError,
+    EvaluationErrorCode, EvaluationOptions, EvaluationResult, StructValue,
+};
+
+enum Executor {
+    Owned(Option<Runtime>),
+    Handle(Handle),
+}
+
+impl Drop for Executor {
+    fn drop(&mut self) {
+        // INFO: Dropping a runtime waits for its tasks, which panics inside an async context
+        if let Self::Owned(runtime) = self {
+            if let Some(runtime) = runtime.take() {
+                runtime.shutdown_background();
+            }
+        }
+    }
+}
+
+/// A [`Client`] for synchronous code, e.g. config loaders, `Drop` impls, rayon workers or build
+/// scripts, blocking the current thread until the evaluation completes.
+///
+/// It must not be called where a tokio runtime is entered, e.g. from an async task, where
+/// blocking could deadlock the executor: the evaluation fails with
+/// [`EvaluationErrorCode::General`] instead. Use the async [`Client`] there.
+///
+/// ```
+/// use open_feature::{BlockingClient, OpenFeature};
+///
+/// let client = BlockingClient::new(OpenFeature::default().create_client());
+///
+/// assert!(client.get_bool_value("my_feature", None, None).is_err());
+/// ```
+#[derive(Clone)]
+pub struct BlockingClient {
+    client: Client,
+    executor: Arc<Executor>,
+}
+
+impl BlockingClient {
+    /// Create a blocking client evaluating `client` on an internal single-threaded runtime.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the runtime cannot be created.
+    pub fn new(client: Client) -> Self {
+        let runtime = Builder::new_current_thread()
+            .enable_all()
+            .build()
+            .expect("failed to create the runtime of the blocking client");
+
+        Self {
+            client,
+            executor: Arc::new(Executor::Owned(Some(runtime))),
+        }
+    }
+
+    /// Create a blocking client evaluating `client` through [`Handle::block_on`], e.g. on the
+    /// runtime the provider was created on.
+    pub fn with_handle(client: Client, handle: Handle) -> Self {
+        Self {
+            client,
+            executor: Arc::new(Executor::Handle(handle)),
+        }
+    }
+
+    /// Return the wrapped async client.
+    pub fn client(&self) -> &Client {
+        &self.client
+    }
+
+    /// Return the metadata of the client.
+    pub fn metadata(&self) -> &ClientMetadata {
+        self.client.metadata()
+    }
+
+    /// Evaluate given `flag_key` as a bool value, see [`Client::get_bool_value`].
+    pub fn get_bool_value(
+        &self,
+        flag_key: &str,
+        evaluation_context: Option<&EvaluationContext>,
+        evaluation_options: Option<&EvaluationOptions>,
+    ) -> EvaluationResult<bool> {
+        self.block_on(
+            self.client
+                .get_bool_value(flag_key, evaluation_context, evaluation_options),
+        )
+    }
+
+    /// Evaluate given `flag_key` as an int (i64) value, see [`Client::get_int_value`].
+    pub fn get_int_value(
+        &self,
+        flag_key: &str,
+        evaluation_context: Option<&EvaluationContext>,
+        evaluation_options: Option<&EvaluationOptions>,
+    ) -> EvaluationResult<i64> {
+        self.block_on(
+            self.client
+                .get_int_value(flag_key, evaluation_context, evaluation_options),
+        )
+    }
+
+    /// Evaluate given `flag_key` as a float (f64) value, see [`Client::get_float_value`].
+    pub fn get_float_value(
+        &self,
+        flag_key: &str,
+        evaluation_context: Option<&EvaluationContext>,
+        evaluation_options: Option<&EvaluationOptions>,
+    ) -> EvaluationResult<f64> {
+        self.block_on(
+            self.client
+                .get_float_value(flag_key, evaluation_context, evaluation_options),
+        )
+    }
+
+    /// Evaluate given `flag_key` as a string value, see [`Client::get_string_value`].
+    pub fn get_string_value(
+        &self,
+        flag_key: &str,
+        evaluation_context: Option<&EvaluationContext>,
+        evaluation_options: Option<&EvaluationOptions>,
+    ) -> EvaluationResult<String> {
+        self.block_on(self.client.get_string_value(
+            flag_key,
+            evaluation_context,
+            evaluation_options,
+        ))
+    }
+
+    /// Evaluate given `flag_key` as a struct, see [`Client::get_struct_value`].
+    pub fn get_struct_value<T: TryFrom<StructValue>>(
+        &self,
+        flag_key: &str,
+        evaluation_context: Option<&EvaluationContext>,
+        evaluation_options: Option<&EvaluationOptions>,
+    ) -> EvaluationResult<T> {
+        self.block_on(self.client.get_struct_value(
+            flag_key,
+            evaluation_context,
+            evaluation_options,
+        ))
+    }
+
+    /// Return the [`EvaluationDetails`] of given `flag_key` as a bool value, see
+    /// [`Client::get_bool_details`].
+    pub fn get_bool_details(
+        &self,
+        flag_key: &str,
+        evaluation_context: Option<&EvaluationContext>,
+        evaluation_options: Option<&EvaluationOptions>,
+    ) -> EvaluationResult<EvaluationDetails<bool>> {
+        self.block_on(self.client.get_bool_details(
+            flag_key,
+            evaluation_context,
+            evaluation_options,
+        ))
+    }
+
+    /// Return the [`EvaluationDetails`] of given `flag_key` as an int (i64) value, see
+    /// [`Client::get_int_details`].
+    pub fn get_int_details(
+        &self,
+        flag_key: &str,
+        evaluation_context: Option<&EvaluationContext>,
+        evaluation_options: Option<&EvaluationOptions>,
+    ) -> EvaluationResult<EvaluationDetails<i64>> {
+        self.block_on(
+            self.client
+                .get_int_details(flag_key, evaluation_context, evaluation_options),
+        )
+    }
+
+    /// Return the [`EvaluationDetails`] of given `flag_key` as a float (f64) value, see
+    /// [`Client::get_float_details`].
+    pub fn get_float_details(
+        &self,
+        flag_key: &str,
+        evaluation_context: Option<&EvaluationContext>,
+        evaluation_options: Option<&EvaluationOptions>,
+    ) -> EvaluationResult<EvaluationDetails<f64>> {
+        self.block_on(self.client.get_float_details(
+            flag_key,
+            evaluation_context,
+            evaluation_options,
+        ))
+    }
+
+    /// Return the [`EvaluationDetails`] of given `flag_key` as a string value, see
+    /// [`Client::get_string_details`].
+    pub fn get_string_details(
+        &self,
+        flag_key: &str,
+        evaluation_context: Option<&EvaluationContext>,
+        evaluation_options: Option<&EvaluationOptions>,
+    ) -> EvaluationResult<EvaluationDetails<String>> {
+        self.block_on(self.client.get_string_details(
+            flag_key,
+            evaluation_context,
+            evaluation_options,
+        ))
+    }
+
+    /// Return the [`EvaluationDetails`] of given `flag_key` as a struct, see
+    /// [`Client::get_struct_details`].
+    pub fn get_struct_details<T: TryFrom<StructValue>>(
+        &self,
+        flag_key: &str,
+        evaluation_context: Option<&EvaluationContext>,
+        evaluation_options: Option<&EvaluationOptions>,
+    ) -> EvaluationResult<EvaluationDetails<T>> {
+        self.block_on(self.client.get_struct_details(
+            flag_key,
+            evaluation_context,
+            evaluation_options,
+        ))
+    }
+
+    fn block_on<T>(
+        &self,
+        future: impl Future<Output = EvaluationResult<T>>,
+    ) -> EvaluationResult<T> {
+        // INFO: Blocking inside a runtime would make tokio panic, or deadlock the executor
+        if Handle::try_current().is_ok() {
+            return Err(EvaluationError::builder()
+                .code(EvaluationErrorCode::General(
+                    "Blocking evaluation in an async context".to_string(),
+                ))
+                .message("The blocking client cannot be used within a tokio runtime, use the async client instead")
+                .build());
+        }
+
+        match &*self.executor {
+            Executor::Owned(Some(runtime)) => runtime.block_on(future),
+            Executor::Owned(None) => unreachable!("the runtime is only taken on drop"),
+            Executor::Handle(handle) => handle.block_on(future),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        provider::{MockFeatureProvider, ProviderMetadata, ResolutionDetails},
+        EvaluationReason, OpenFeature,
+    };
+
+    use super::*;
+
+    fn create_client() -> Client {
+        let mut provider = MockFeatureProvider::new();
+        provider.expect_initialize().returning(|_| {});
+        provider.expect_hooks().return_const(vec![]);
+        provider
+            .expect_metadata()
+            .return_const(ProviderMetadata::default());
+        provider
+            .expect_resolve_int_value()
+            .return_const(Ok(ResolutionDetails::builder()
+                .value(42)
+                .reason(EvaluationReason::Static)
+                .build()));
+
+        let mut api = OpenFeature::default();
+        futures_executor::block_on(api.set_provider(provider));
+        api.create_client()
+    }
+
+    #[test]
+    fn evaluate_blocking() {
+        let client = BlockingClient::new(create_client());
+
+        assert_eq!(client.get_int_value("flag", None, None), Ok(42));
+        assert_eq!(
+            client.get_int_details("flag", None, None).unwrap().reason,
+            Some(EvaluationReason::Static)
+        );
+        assert!(client.get_bool_value("flag", None, None).is_err());
+    }
+
+    #[test]
+    fn evaluate_through_handle() {
+        let runtime = Builder::new_current_thread().build().unwrap();
+        let client = BlockingClient::with_handle(create_client(), runtime.handle().clone());
+
+        assert_eq!(client.get_int_value("flag", None, None), Ok(42));
+    }
+
+    #[tokio::test(flavor = "multi_thread")]
+    async fn evaluate_from_thread() {
+        let client = BlockingClient::with_handle(create_client(), Handle::current());
+
+        let value = std::thread::spawn(move || client.get_int_value("flag", None, None))
+            .join()
+            .unwrap();
+
+        assert_eq!(value, Ok(42));
+    }
+
+    #[tokio::test]
+    async fn reject_async_context() {
+        let client = BlockingClient::new(create_client());
+
+        let error = client.get_int_value("flag", None, None).unwrap_err();
+        assert_eq!(
+            error.code,
+            EvaluationErrorCode::General("Blocking evaluation in an async context".to_string())
+        );
+    }
+}
@@ -0,0 +1,171 @@
+use std::collections::HashMap;
+
+use crate::{provider::FeatureProvider, EvaluationContext, HookWrapper, OpenFeature};
+
+use super::provider_registry::PendingProvider;
+
+// ============================================================
+//  OpenFeatureBuilder
+// ============================================================
+
+/// The builder of an owned [`OpenFeature`] instance, created through [`OpenFeature::builder()`].
+///
+/// The instance is independent from the singleton: it can be shared with an `Arc`, used by
+/// tests running in parallel, or made the singleton with [`OpenFeature::install_global`].
+#[derive(Default)]
+pub struct OpenFeatureBuilder {
+    providers: HashMap<String, PendingProvider>,
+    hooks: Vec<HookWrapper>,
+    evaluation_context: EvaluationContext,
+}
+
+impl OpenFeatureBuilder {
+    /// Set the default provider.
+    #[must_use]
+    pub fn provider<T: FeatureProvider>(mut self, provider: T) -> Self {
+        self.providers
+            .insert(String::default(), PendingProvider::new(provider));
+        self
+    }
+
+    /// Bind the given `provider` to the corresponding `domain`.
+    #[must_use]
+    pub fn named_provider<T: FeatureProvider>(
+        mut self,
+        domain: impl Into<String>,
+        provider: T,
+    ) -> Self {
+        self.providers
+            .insert(domain.into(), PendingProvider::new(provider));
+        self
+    }
+
+    /// Add a new hook to the global list of hooks.
+    #[must_use]
+    pub fn hook<T: Into<HookWrapper>>(mut self, hook: T) -> Self {
+        self.hooks.push(hook.into());
+        self
+    }
+
+    /// Set the global evaluation context.
+    #[must_use]
+    pub fn context(mut self, evaluation_context: EvaluationContext) -> Self {
+        self.evaluation_context = evaluation_context;
+        self
+    }
+
+    /// Build the instance, once every provider is initialized with the global evaluation context.
+    pub async fn build(self) -> OpenFeature {
+        let mut api = OpenFeature::default();
+
+        api.set_evaluation_context(self.evaluation_context).await;
+
+        for hook in self.hooks {
+            api.add_hook(hook).await;
+        }
+
+        for (name, provider) in self.providers {
+            api.bind_provider(&name, provider).await;
+        }
+
+        api
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::{
+        atomic::{AtomicUsize, Ordering},
+        Arc,
+    };
+
+    use mockall::predicate;
+
+    use crate::{
+        provider::{MockFeatureProvider, ProviderMetadata, ResolutionDetails},
+        FnHook,
+    };
+
+    use super::*;
+
+    fn provider(name: &str, value: i64) -> MockFeatureProvider {
+        let mut provider = MockFeatureProvider::new();
+        provider
+            .expect_initialize()
+            .with(predicate::eq(
+                EvaluationContext::default().with_targeting_key("user-1"),
+            ))
+            .once()
+            .returning(|_| {});
+        provider.expect_hooks().return_const(vec![]);
+        provider
+            .expect_metadata()
+            .return_const(ProviderMetadata::new(name));
+        provider
+            .expect_resolve_int_value()
+            .return_const(Ok(ResolutionDetails::new(value)));
+        provider
+    }
+
+    #[tokio::test]
+    async fn build() {
+        let evaluations = Arc::new(AtomicUsize::new(0));
+        let hook = {
+            let evaluations = evaluations.clone();
+            FnHook::finally_fn(move |_, _, _| {
+                evaluations.fetch_add(1, Ordering::SeqCst);
+            })
+        };
+
+        let api = Arc::new(
+            OpenFeature::builder()
+                .provider(provider("Default", 1))
+                .named_provider("domain", provider("Named", 2))
+                .hook(hook)
+                .context(EvaluationContext::default().with_targeting_key("user-1"))
+                .build()
+                .await,
+        );
+
+        assert_eq!(api.provider_metadata().await.name, "Default");
+        assert_eq!(
+            api.create_client().get_int_value("flag", None, None).await,
+            Ok(1)
+        );
+        assert_eq!(
+            api.create_named_client("domain")
+                .get_int_value("flag", None, None)
+                .await,
+            Ok(2)
+        );
+        assert_eq!(evaluations.load(Ordering::SeqCst), 2);
+    }
+
+    #[tokio::test]
+    async fn install_global() {
+        let _lock = crate::api::api::SINGLETON_TEST_LOCK.lock().await;
+
+        let mut provider = MockFeatureProvider::new();
+        provider.expect_initialize().returning(|_| {});
+        provider.expect_on_context_changed().return_const(());
+        provider
+            .expect_metadata()
+            .return_const(ProviderMetadata::new("Installed"));
+
+        OpenFeature::builder()
+            .named_provider("install_global", provider)
+            .build()
+            .await
+            .install_global()
+            .await;
+
+        assert_eq!(
+            OpenFeature::singleton()
+                .await
+                .named_provider_metadata("install_global")
+                .await
+                .map(|metadata| metadata.name),
+            Some("Installed".to_string())
+        );
+    }
+}
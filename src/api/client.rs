@@ -1,16 +1,35 @@
-use std::{future::Future, pin::Pin, sync::Arc};
+use std::{collections::HashMap, future::Future, pin::Pin, sync::Arc};
 
 use crate::{
-    provider::{FeatureProvider, ResolutionDetails},
+    provider::{FeatureProvider, ProviderStatus, ResolutionDetails, SyncFeatureProvider},
     EvaluationContext, EvaluationDetails, EvaluationError, EvaluationErrorCode, EvaluationOptions,
-    EvaluationResult, Hook, HookContext, HookHints, HookWrapper, StructValue, Value,
+    EvaluationResult, FlagRequest, HookContext, HookData, HookHints, HookWrapper, StructValue,
+    Type, Value,
+};
+#[cfg(feature = "tokio")]
+use {
+    crate::{provider::ProviderEvent, EvaluationReason, FlagMetadata},
+    async_broadcast::{Receiver, RecvError},
+    tokio::sync::watch,
 };
 
 use super::{
-    global_evaluation_context::GlobalEvaluationContext, global_hooks::GlobalHooks,
+    evaluation_events::EvaluationEvents,
+    flag::{Flag, FlagType},
+    global_evaluation_context::GlobalEvaluationContext,
+    global_hooks::GlobalHooks,
+    isolation::{isolate, isolate_sync},
     provider_registry::ProviderRegistry,
 };
 
+/// The synchronous resolution of a flag type, used for the providers adapted by a
+/// [`SyncProvider`](crate::provider::SyncProvider).
+type ResolveSyncFn<T> = for<'a> fn(
+    &'a dyn SyncFeatureProvider,
+    &'a str,
+    &'a EvaluationContext,
+) -> EvaluationResult<ResolutionDetails<T>>;
+
 /// The metadata of OpenFeature client.
 #[derive(Clone, Default, PartialEq, Debug)]
 pub struct ClientMetadata {
@@ -20,15 +39,21 @@ pub struct ClientMetadata {
 
 /// The OpenFeature client.
 /// Create it through the [`OpenFeature`] struct.
+///
+/// A client is cheap to clone and can be shared between tasks, e.g. as the state of a web
+/// server. Use [`Client::with_context`] and [`Client::with_hooks`] to derive a client scoped to a
+/// request.
 #[allow(clippy::struct_field_names)]
+#[derive(Clone)]
 pub struct Client {
-    metadata: ClientMetadata,
+    metadata: Arc<ClientMetadata>,
     provider_registry: ProviderRegistry,
-    evaluation_context: EvaluationContext,
+    evaluation_context: Arc<EvaluationContext>,
     global_evaluation_context: GlobalEvaluationContext,
     global_hooks: GlobalHooks,
+    evaluation_events: EvaluationEvents,
 
-    client_hooks: Vec<HookWrapper>,
+    client_hooks: Arc<Vec<HookWrapper>>,
 }
 
 impl Client {
@@ -37,15 +62,17 @@ impl Client {
         name: impl Into<String>,
         global_evaluation_context: GlobalEvaluationContext,
         global_hooks: GlobalHooks,
+        evaluation_events: EvaluationEvents,
         provider_registry: ProviderRegistry,
     ) -> Self {
         Self {
-            metadata: ClientMetadata { name: name.into() },
+            metadata: Arc::new(ClientMetadata { name: name.into() }),
             global_evaluation_context,
             global_hooks,
+            evaluation_events,
             provider_registry,
-            evaluation_context: EvaluationContext::default(),
-            client_hooks: Vec::new(),
+            evaluation_context: Arc::default(),
+            client_hooks: Arc::default(),
         }
     }
 
@@ -56,7 +83,36 @@ impl Client {
 
     /// Set evaluation context to the client.
     pub fn set_evaluation_context(&mut self, evaluation_context: EvaluationContext) {
-        self.evaluation_context = evaluation_context;
+        self.evaluation_context = Arc::new(evaluation_context);
+    }
+
+    /// Return a new client sharing the providers and the hooks of this one, with
+    /// `evaluation_context` merged over its evaluation context.
+    #[must_use]
+    pub fn with_context(&self, mut evaluation_context: EvaluationContext) -> Self {
+        evaluation_context.merge_missing(&self.evaluation_context);
+
+        Self {
+            evaluation_context: Arc::new(evaluation_context),
+            ..self.clone()
+        }
+    }
+
+    /// Return a new client sharing the providers and the evaluation context of this one, with
+    /// `hooks` run after its own hooks.
+    #[must_use]
+    pub fn with_hooks<I>(&self, hooks: I) -> Self
+    where
+        I: IntoIterator,
+        I::Item: Into<HookWrapper>,
+    {
+        let mut client_hooks = Vec::clone(&self.client_hooks);
+        client_hooks.extend(hooks.into_iter().map(Into::into));
+
+        Self {
+            client_hooks: Arc::new(client_hooks),
+            ..self.clone()
+        }
     }
 
     /// Evaluate given `flag_key` with corresponding `evaluation_context` and `evaluation_options`
@@ -144,13 +200,14 @@ impl Client {
         evaluation_context: Option<&EvaluationContext>,
         evaluation_options: Option<&EvaluationOptions>,
     ) -> EvaluationResult<EvaluationDetails<bool>> {
-        let context = self.merge_evaluation_context(evaluation_context).await;
+        let context = self.merge_evaluation_context(evaluation_context);
 
         self.evaluate(
             flag_key,
             &context,
             evaluation_options,
             call_resolve_bool_value,
+            <dyn SyncFeatureProvider>::resolve_bool_value,
         )
         .await
     }
@@ -163,13 +220,14 @@ impl Client {
         evaluation_context: Option<&EvaluationContext>,
         evaluation_options: Option<&EvaluationOptions>,
     ) -> EvaluationResult<EvaluationDetails<i64>> {
-        let context = self.merge_evaluation_context(evaluation_context).await;
+        let context = self.merge_evaluation_context(evaluation_context);
 
         self.evaluate(
             flag_key,
             &context,
             evaluation_options,
             call_resolve_int_value,
+            <dyn SyncFeatureProvider>::resolve_int_value,
         )
         .await
     }
@@ -182,13 +240,14 @@ impl Client {
         evaluation_context: Option<&EvaluationContext>,
         evaluation_options: Option<&EvaluationOptions>,
     ) -> EvaluationResult<EvaluationDetails<f64>> {
-        let context = self.merge_evaluation_context(evaluation_context).await;
+        let context = self.merge_evaluation_context(evaluation_context);
 
         self.evaluate(
             flag_key,
             &context,
             evaluation_options,
             call_resolve_float_value,
+            <dyn SyncFeatureProvider>::resolve_float_value,
         )
         .await
     }
@@ -201,13 +260,14 @@ impl Client {
         evaluation_context: Option<&EvaluationContext>,
         evaluation_options: Option<&EvaluationOptions>,
     ) -> EvaluationResult<EvaluationDetails<String>> {
-        let context = self.merge_evaluation_context(evaluation_context).await;
+        let context = self.merge_evaluation_context(evaluation_context);
 
         self.evaluate(
             flag_key,
             &context,
             evaluation_options,
             call_resolve_string_value,
+            <dyn SyncFeatureProvider>::resolve_string_value,
         )
         .await
     }
@@ -220,7 +280,7 @@ impl Client {
         evaluation_context: Option<&EvaluationContext>,
         evaluation_options: Option<&EvaluationOptions>,
     ) -> EvaluationResult<EvaluationDetails<T>> {
-        let context = self.merge_evaluation_context(evaluation_context).await;
+        let context = self.merge_evaluation_context(evaluation_context);
 
         let result = self
             .evaluate(
@@ -228,6 +288,7 @@ impl Client {
                 &context,
                 evaluation_options,
                 call_resolve_struct_value,
+                <dyn SyncFeatureProvider>::resolve_struct_value,
             )
             .await?;
 
@@ -238,6 +299,8 @@ impl Client {
                 reason: result.reason,
                 variant: result.variant,
                 flag_metadata: result.flag_metadata,
+                error_code: None,
+                error_message: None,
             }),
             Err(_) => Err(EvaluationError {
                 code: EvaluationErrorCode::TypeMismatch,
@@ -246,13 +309,135 @@ impl Client {
         }
     }
 
-    async fn get_provider(&self) -> Arc<dyn FeatureProvider> {
-        self.provider_registry.get(&self.metadata.name).await.get()
+    /// Evaluate given typed `flag` with corresponding `evaluation_context`.
+    /// If the evaluation fails, the default value of the flag is returned.
+    pub async fn get<T: FlagType>(
+        &self,
+        flag: &Flag<T>,
+        evaluation_context: Option<&EvaluationContext>,
+    ) -> T::Value {
+        match self.details(flag, evaluation_context).await {
+            Ok(details) => details.value,
+            Err(_) => flag.default_value().to_value(),
+        }
+    }
+
+    /// Return the [`EvaluationDetails`] of given typed `flag` with corresponding
+    /// `evaluation_context`.
+    ///
+    /// The hooks of the flag run after the client hooks, and its metadata completes the one
+    /// returned by the provider. Hooks and evaluation events see the default value of the flag.
+    pub async fn details<T: FlagType>(
+        &self,
+        flag: &Flag<T>,
+        evaluation_context: Option<&EvaluationContext>,
+    ) -> EvaluationResult<EvaluationDetails<T::Value>> {
+        let evaluation_options = EvaluationOptions {
+            default_value: flag.default_value().to_default_value(),
+            ..EvaluationOptions::new(flag.hooks().to_vec(), HookHints::default())
+        };
+
+        let mut details = T::evaluate(
+            self,
+            flag.key(),
+            evaluation_context,
+            Some(&evaluation_options),
+        )
+        .await?;
+
+        if let Some(metadata) = flag.metadata() {
+            for (key, value) in &metadata.values {
+                details
+                    .flag_metadata
+                    .values
+                    .entry(key.clone())
+                    .or_insert_with(|| value.clone());
+            }
+        }
+
+        Ok(details)
+    }
+
+    /// Evaluate the flags of `requests` in a batch with corresponding `evaluation_context` and
+    /// `evaluation_options`, returning the results in the same order.
+    ///
+    /// The evaluation context is merged once, and the flags are resolved with a single call to
+    /// [`FeatureProvider::resolve_all`] if the provider supports it, or one by one otherwise.
+    /// Hooks still run for every flag; a flag whose context is changed by a before hook is
+    /// resolved on its own, or out of another bulk resolution with that context for array flags.
+    pub async fn evaluate_many(
+        &self,
+        requests: &[FlagRequest],
+        evaluation_context: Option<&EvaluationContext>,
+        evaluation_options: Option<&EvaluationOptions>,
+    ) -> Vec<EvaluationResult<EvaluationDetails<Value>>> {
+        let context = self.merge_evaluation_context(evaluation_context);
+        let provider = self.get_provider();
+
+        self.evaluate_batch(&*provider, requests, &context, evaluation_options, None)
+            .await
+    }
+
+    /// Evaluate all the flags of the provider with corresponding `evaluation_context` and
+    /// `evaluation_options`, keyed by flag key.
+    ///
+    /// The flags are discovered with a single call to [`FeatureProvider::resolve_all`], and hooks
+    /// run for every flag as in [`Client::evaluate_many`]. An error is returned if the provider
+    /// does not support bulk resolution, or if it fails.
+    pub async fn evaluate_all(
+        &self,
+        evaluation_context: Option<&EvaluationContext>,
+        evaluation_options: Option<&EvaluationOptions>,
+    ) -> EvaluationResult<HashMap<String, EvaluationResult<EvaluationDetails<Value>>>> {
+        let context = self.merge_evaluation_context(evaluation_context);
+        let provider = self.get_provider();
+
+        let resolved = isolate(None, async { provider.resolve_all(&context).await })
+            .await
+            .unwrap_or_else(|interrupted| Some(Err(interrupted.into_error("Provider"))))
+            .ok_or_else(|| {
+                EvaluationError::builder()
+                    .code(EvaluationErrorCode::General(
+                        "Bulk resolution not supported".to_string(),
+                    ))
+                    .message(format!(
+                        "Provider {} cannot resolve all flags at once",
+                        provider.metadata().name
+                    ))
+                    .build()
+            })??;
+
+        let requests: Vec<FlagRequest> = resolved
+            .iter()
+            .map(|(flag_key, details)| {
+                FlagRequest::new(flag_key.clone(), default_value(&details.value.get_type()))
+            })
+            .collect();
+
+        let results = self
+            .evaluate_batch(
+                &*provider,
+                &requests,
+                &context,
+                evaluation_options,
+                Some(Ok(resolved)),
+            )
+            .await;
+
+        Ok(requests
+            .into_iter()
+            .map(|request| request.flag_key)
+            .zip(results)
+            .collect())
+    }
+
+    fn get_provider(&self) -> Arc<dyn FeatureProvider> {
+        self.provider_registry.get(&self.metadata.name).get()
     }
 
     /// Merge provided `flag_evaluation_context` (that is passed when evaluating a flag) with
     /// client and global evaluation context.
-    async fn merge_evaluation_context(
+    fn merge_evaluation_context(
         &self,
         flag_evaluation_context: Option<&EvaluationContext>,
     ) -> EvaluationContext {
@@ -263,7 +448,7 @@ impl Client {
 
         context.merge_missing(&self.evaluation_context);
 
-        let global_evaluation_context = self.global_evaluation_context.get().await;
+        let global_evaluation_context = self.global_evaluation_context.get();
 
         context.merge_missing(&global_evaluation_context);
 
@@ -273,21 +458,59 @@ impl Client {
 
 impl Client {
     /// Add a hook to the client.
+    /// Pass a [`HookWrapper`] to configure how the hook is executed, e.g. with a timeout.
     #[must_use]
-    pub fn with_hook<T: Hook>(mut self, hook: T) -> Self {
-        self.client_hooks.push(HookWrapper::new(hook));
+    pub fn with_hook<T: Into<HookWrapper>>(mut self, hook: T) -> Self {
+        Arc::make_mut(&mut self.client_hooks).push(hook.into());
         self
     }
 
     /// Add logging hook to the client.
     #[must_use]
     pub fn with_logging_hook(self, include_evaluation_context: bool) -> Self {
-        self.with_hook(crate::LoggingHook {
-            include_evaluation_context,
-        })
+        self.with_hook(
+            crate::LoggingHook::builder()
+                .include_evaluation_context(include_evaluation_context)
+                .build(),
+        )
     }
 
     async fn evaluate<T>(
+        &self,
+        flag_key: &str,
+        context: &EvaluationContext,
+        evaluation_options: Option<&EvaluationOptions>,
+        resolve: impl for<'a> FnOnce(
+            &'a dyn FeatureProvider,
+            &'a str,
+            &'a EvaluationContext,
+        ) -> Pin<
+            Box<dyn Future<Output = EvaluationResult<ResolutionDetails<T>>> + Send + 'a>,
+        >,
+        resolve_sync: ResolveSyncFn<T>,
+    ) -> EvaluationResult<EvaluationDetails<T>>
+    where
+        T: Into<Value> + Clone + Default,
+    {
+        let evaluation =
+            self.evaluate_with_hooks(flag_key, context, evaluation_options, resolve, resolve_sync);
+
+        // INFO: Let events of hooks and providers nest under the evaluation
+        #[cfg(feature = "tracing")]
+        let evaluation = tracing::Instrument::instrument(
+            evaluation,
+            tracing::debug_span!(
+                target: "open_feature",
+                "feature_flag.evaluation",
+                domain = %self.metadata.name,
+                flag_key,
+            ),
+        );
+
+        evaluation.await
+    }
+
+    async fn evaluate_with_hooks<T>(
         &self,
         flag_key: &str,
         context: &EvaluationContext,
@@ -299,104 +522,269 @@ impl Client {
         ) -> Pin<
             Box<dyn Future<Output = EvaluationResult<ResolutionDetails<T>>> + Send + 'a>,
         >,
+        resolve_sync: ResolveSyncFn<T>,
     ) -> EvaluationResult<EvaluationDetails<T>>
     where
         T: Into<Value> + Clone + Default,
     {
-        let provider = self.get_provider().await;
+        let wrapper = self.provider_registry.get(&self.metadata.name);
+        let provider = wrapper.get();
         let hints = evaluation_options.map(|options| &options.hints);
 
-        let default: Value = T::default().into();
+        // INFO: The declared default of a typed flag, or the zero value of the evaluated type
+        let zero_value: Value = T::default().into();
+        let default_value = evaluation_options
+            .and_then(|options| options.default_value.clone())
+            .filter(|value| value.get_type() == zero_value.get_type())
+            .unwrap_or(zero_value);
 
-        let mut hook_context = HookContext {
+        let mut hook_context = self.hook_context(&*provider, flag_key, default_value, context);
+
+        let global_hooks = self.global_hooks.get();
+        let hooks = Self::hooks(
+            &global_hooks,
+            &self.client_hooks,
+            evaluation_options,
+            &*provider,
+        );
+        let before_hooks = hooks.iter().map(|(hook, data)| (*hook, data));
+
+        // INFO: Hooks called after the resolution are in reverse order
+        // Provider, Invocation, Client, API(global)
+        let after_hooks = before_hooks.clone().rev();
+
+        let (context, result) = self
+            .before_hooks(before_hooks.into_iter(), &hook_context, hints)
+            .await;
+        hook_context.evaluation_context = &context;
+
+        if let Err(error) = result {
+            self.resolution_hooks(after_hooks, &hook_context, Err(&error), hints)
+                .await;
+
+            return Err(error);
+        }
+
+        // INFO: Run the resolution, a panicking provider is reported as an error
+        let result = match wrapper.get_sync() {
+            Some(sync) => isolate_sync(|| resolve_sync(&*sync, flag_key, &context)),
+            None => {
+                isolate(None, async {
+                    resolve(&*provider, flag_key, &context).await
+                })
+                .await
+            }
+        }
+        .unwrap_or_else(|interrupted| Err(interrupted.into_error("Provider")))
+        .map(|details| details.into_evaluation_details(flag_key));
+
+        self.resolution_hooks(
+            after_hooks,
+            &hook_context,
+            result.as_ref().map(|details| details.clone().into_value()),
+            hints,
+        )
+        .await;
+
+        result
+    }
+
+    /// Evaluate `requests` with the already merged `context`, reusing the bulk resolution
+    /// `resolved` if the provider has already been called.
+    async fn evaluate_batch(
+        &self,
+        provider: &dyn FeatureProvider,
+        requests: &[FlagRequest],
+        context: &EvaluationContext,
+        evaluation_options: Option<&EvaluationOptions>,
+        mut resolved: Option<EvaluationResult<HashMap<String, ResolutionDetails<Value>>>>,
+    ) -> Vec<EvaluationResult<EvaluationDetails<Value>>> {
+        let hints = evaluation_options.map(|options| &options.hints);
+        let global_hooks = self.global_hooks.get();
+
+        // INFO: Run the before hooks of every flag ahead of the resolution
+        let mut evaluations = Vec::with_capacity(requests.len());
+        for request in requests {
+            let hooks = Self::hooks(
+                &global_hooks,
+                &self.client_hooks,
+                evaluation_options,
+                provider,
+            );
+            let hook_context = self.hook_context(
+                provider,
+                &request.flag_key,
+                request.default_value.clone(),
+                context,
+            );
+
+            let (flag_context, result) = self
+                .before_hooks(
+                    hooks.iter().map(|(hook, data)| (*hook, data)),
+                    &hook_context,
+                    hints,
+                )
+                .await;
+            evaluations.push((hooks, flag_context, result));
+        }
+
+        // INFO: A single provider call for all the flags still evaluated with the shared context
+        if resolved.is_none()
+            && evaluations
+                .iter()
+                .any(|(_, flag_context, result)| result.is_ok() && flag_context == context)
+        {
+            resolved = isolate(None, async { provider.resolve_all(context).await })
+                .await
+                .unwrap_or_else(|interrupted| Some(Err(interrupted.into_error("Provider"))));
+        }
+
+        // INFO: The bulk resolutions of the contexts changed by the before hooks, for the array
+        // flags that cannot be resolved on their own
+        let mut resolved_by_context = Vec::new();
+
+        let mut results = Vec::with_capacity(requests.len());
+        for (request, (hooks, flag_context, result)) in requests.iter().zip(evaluations) {
+            let hook_context = self.hook_context(
+                provider,
+                &request.flag_key,
+                request.default_value.clone(),
+                &flag_context,
+            );
+            let after_hooks = hooks.iter().map(|(hook, data)| (*hook, data)).rev();
+
+            let result = match (result, &resolved) {
+                (Err(error), _) => Err(error),
+                (Ok(()), Some(resolved)) if flag_context == *context => {
+                    pick_resolved(resolved, request)
+                }
+                (Ok(()), _) if request.flag_type() == Type::Array => {
+                    let known = resolved_by_context
+                        .iter()
+                        .position(|(resolved_context, _)| *resolved_context == flag_context);
+                    let index = if let Some(index) = known {
+                        index
+                    } else {
+                        let resolved =
+                            isolate(None, async { provider.resolve_all(&flag_context).await })
+                                .await
+                                .unwrap_or_else(|interrupted| {
+                                    Some(Err(interrupted.into_error("Provider")))
+                                });
+                        resolved_by_context.push((flag_context.clone(), resolved));
+                        resolved_by_context.len() - 1
+                    };
+
+                    match &resolved_by_context[index].1 {
+                        Some(resolved) => pick_resolved(resolved, request),
+                        None => Err(array_not_in_bulk()),
+                    }
+                }
+                (Ok(()), _) => isolate(None, async {
+                    resolve_value(provider, request, &flag_context).await
+                })
+                .await
+                .unwrap_or_else(|interrupted| Err(interrupted.into_error("Provider")))
+                .map(|details| details.into_evaluation_details(&request.flag_key)),
+            };
+
+            self.resolution_hooks(after_hooks, &hook_context, result.as_ref().cloned(), hints)
+                .await;
+            results.push(result);
+        }
+
+        results
+    }
+
+    fn hook_context<'a>(
+        &self,
+        provider: &dyn FeatureProvider,
+        flag_key: &'a str,
+        default: Value,
+        context: &'a EvaluationContext,
+    ) -> HookContext<'a> {
+        HookContext {
             flag_key,
             flag_type: default.get_type(),
-            client_metadata: self.metadata.clone(),
+            client_metadata: ClientMetadata::clone(&self.metadata),
             provider_metadata: provider.metadata().clone(),
             evaluation_context: context,
 
             default_value: Some(default),
-        };
+            hook_data: HookData::default(),
+        }
+    }
 
-        let global_hooks = self.global_hooks.get().await;
-        let client_hooks = &self.client_hooks[..];
+    /// Return the hooks of an evaluation in the order of the before stage.
+    /// Each hook gets its own data, shared between its stages of this evaluation.
+    fn hooks<'a>(
+        global_hooks: &'a [HookWrapper],
+        client_hooks: &'a [HookWrapper],
+        evaluation_options: Option<&'a EvaluationOptions>,
+        provider: &'a dyn FeatureProvider,
+    ) -> Vec<(&'a HookWrapper, HookData)> {
         let invocation_hooks: &[HookWrapper] = evaluation_options
             .map(|options| options.hooks.as_ref())
             .unwrap_or_default();
-        let provider_hooks = provider.hooks();
 
         // INFO: API(global), Client, Invocation, Provider
         // https://github.com/open-feature/spec/blob/main/specification/sections/04-hooks.md#requirement-442
-        let before_hooks = global_hooks
+        global_hooks
             .iter()
             .chain(client_hooks.iter())
             .chain(invocation_hooks.iter())
-            .chain(provider_hooks.iter());
-
-        // INFO: Hooks called after the resolution are in reverse order
-        // Provider, Invocation, Client, API(global)
-        let after_hooks = before_hooks.clone().rev();
-
-        let (context, result) = self
-            .before_hooks(before_hooks.into_iter(), &hook_context, hints)
-            .await;
-        hook_context.evaluation_context = &context;
-
-        // INFO: Result of the resolution or error reason with default value
-        // This bind is defined here to minimize cloning of the `Value`
-        let evaluation_details;
+            .chain(provider.hooks().iter())
+            .map(|hook| (hook, HookData::default()))
+            .collect()
+    }
 
-        if let Err(error) = result {
-            self.error_hooks(after_hooks.clone(), &hook_context, &error, hints)
-                .await;
-            evaluation_details = EvaluationDetails::error_reason(flag_key, T::default());
-            self.finally_hooks(
-                after_hooks.into_iter(),
-                &hook_context,
-                &evaluation_details,
-                hints,
+    /// Run the after, error and finally stages once the flag is resolved, or once a before hook
+    /// failed, then publish the evaluation.
+    async fn resolution_hooks<'a, I>(
+        &self,
+        hooks: I,
+        hook_context: &HookContext<'_>,
+        result: Result<EvaluationDetails<Value>, &EvaluationError>,
+        hints: Option<&HookHints>,
+    ) where
+        I: Iterator<Item = (&'a HookWrapper, &'a HookData)> + Clone,
+    {
+        let error_reason = |error: &EvaluationError| {
+            EvaluationDetails::from_error(
+                hook_context.flag_key,
+                hook_context
+                    .default_value
+                    .clone()
+                    .expect("evaluations always have a default value"),
+                error,
             )
-            .await;
-
-            return Err(error);
-        }
-
-        // INFO: Run the resolution
-        let result = resolve(&*provider, flag_key, &context)
-            .await
-            .map(|details| details.into_evaluation_details(flag_key));
+        };
 
-        // INFO: Run the after hooks
-        match result {
-            Ok(ref details) => {
-                let details = details.clone().into_value();
+        // INFO: Result of the resolution or error reason with default value
+        let evaluation_details = match result {
+            Ok(details) => {
                 if let Err(error) = self
-                    .after_hooks(after_hooks.clone(), &hook_context, &details, hints)
+                    .after_hooks(hooks.clone(), hook_context, &details, hints)
                     .await
                 {
-                    evaluation_details = EvaluationDetails::error_reason(flag_key, T::default());
-                    self.error_hooks(after_hooks.clone(), &hook_context, &error, hints)
+                    self.error_hooks(hooks.clone(), hook_context, &error, hints)
                         .await;
+                    error_reason(&error)
                 } else {
-                    evaluation_details = details;
+                    details
                 }
             }
-            Err(ref error) => {
-                evaluation_details = EvaluationDetails::error_reason(flag_key, T::default());
-                self.error_hooks(after_hooks.clone(), &hook_context, error, hints)
+            Err(error) => {
+                self.error_hooks(hooks.clone(), hook_context, error, hints)
                     .await;
+                error_reason(error)
             }
-        }
-
-        self.finally_hooks(
-            after_hooks.into_iter(),
-            &hook_context,
-            &evaluation_details,
-            hints,
-        )
-        .await;
+        };
 
-        result
+        self.finally_hooks(hooks, hook_context, &evaluation_details, hints)
+            .await;
+        self.evaluation_events
+            .publish(hook_context, &evaluation_details);
     }
 
     async fn before_hooks<'a, I>(
@@ -406,15 +794,22 @@ impl Client {
         hints: Option<&HookHints>,
     ) -> (EvaluationContext, EvaluationResult<()>)
     where
-        I: Iterator<Item = &'a HookWrapper>,
+        I: Iterator<Item = (&'a HookWrapper, &'a HookData)>,
     {
         let mut context = hook_context.evaluation_context.clone();
-        for hook in hooks {
+        for (hook, hook_data) in hooks {
             let invoke_hook_context = HookContext {
                 evaluation_context: &context,
+                hook_data: hook_data.clone(),
                 ..hook_context.clone()
             };
-            match hook.before(&invoke_hook_context, hints).await {
+            let result = isolate(hook.timeout(), async {
+                hook.before(&invoke_hook_context, hints).await
+            })
+            .await
+            .unwrap_or_else(|interrupted| Err(interrupted.into_error("Before hook")));
+
+            match result {
                 Ok(Some(output)) => context = output,
                 Ok(None) => { /* INFO: just continue execution */ }
                 Err(error) => {
@@ -437,10 +832,15 @@ impl Client {
         hints: Option<&HookHints>,
     ) -> EvaluationResult<()>
     where
-        I: Iterator<Item = &'a HookWrapper>,
+        I: Iterator<Item = (&'a HookWrapper, &'a HookData)>,
     {
-        for hook in hooks {
-            hook.after(hook_context, details, hints).await?;
+        for (hook, hook_data) in hooks {
+            let hook_context = hook_context.with_hook_data(hook_data);
+            isolate(hook.timeout(), async {
+                hook.after(&hook_context, details, hints).await
+            })
+            .await
+            .unwrap_or_else(|interrupted| Err(interrupted.into_error("After hook")))?;
         }
 
         Ok(())
@@ -453,10 +853,15 @@ impl Client {
         error: &EvaluationError,
         hints: Option<&HookHints>,
     ) where
-        I: Iterator<Item = &'a HookWrapper>,
+        I: Iterator<Item = (&'a HookWrapper, &'a HookData)>,
     {
-        for hook in hooks {
-            hook.error(hook_context, error, hints).await;
+        // INFO: A failing error hook must not prevent the remaining ones from running
+        for (hook, hook_data) in hooks {
+            let hook_context = hook_context.with_hook_data(hook_data);
+            let _ = isolate(hook.timeout(), async {
+                hook.error(&hook_context, error, hints).await;
+            })
+            .await;
         }
     }
 
@@ -467,10 +872,330 @@ impl Client {
         evaluation_details: &EvaluationDetails<Value>,
         hints: Option<&HookHints>,
     ) where
-        I: Iterator<Item = &'a HookWrapper>,
+        I: Iterator<Item = (&'a HookWrapper, &'a HookData)>,
     {
-        for hook in hooks {
-            hook.finally(hook_context, evaluation_details, hints).await;
+        // INFO: A failing finally hook must not prevent the remaining ones from running
+        for (hook, hook_data) in hooks {
+            let hook_context = hook_context.with_hook_data(hook_data);
+            let _ = isolate(hook.timeout(), async {
+                hook.finally(&hook_context, evaluation_details, hints).await;
+            })
+            .await;
+        }
+    }
+}
+
+// ============================================================
+//  Static context
+// ============================================================
+
+impl Client {
+    /// Return the status of the provider bound to the client.
+    pub fn provider_status(&self) -> ProviderStatus {
+        self.provider_registry.get(&self.metadata.name).status()
+    }
+
+    /// Evaluate given `flag_key` as a bool value, synchronously.
+    ///
+    /// This is the evaluation of the static context paradigm, e.g. for a CLI or a desktop
+    /// application serving one user: the value is answered by
+    /// [`FeatureProvider::resolve_cached_value`] out of the state the provider cached for the
+    /// global evaluation context, set with
+    /// [`OpenFeature::set_evaluation_context`](crate::OpenFeature::set_evaluation_context).
+    /// The evaluation context of the client is not taken into account.
+    ///
+    /// No hook runs, neither the API, client, invocation nor provider ones, as they are
+    /// asynchronous, and no evaluation event is published. Use the async getters where hooks
+    /// must observe every evaluation, e.g. for logging or telemetry.
+    pub fn get_bool_value_sync(&self, flag_key: &str) -> EvaluationResult<bool> {
+        match self.resolve_cached_value(flag_key)? {
+            Value::Bool(value) => Ok(value),
+            value => Err(type_mismatch(&Type::Bool, &value)),
+        }
+    }
+
+    /// Evaluate given `flag_key` as an int (i64) value, synchronously, see
+    /// [`Client::get_bool_value_sync`].
+    pub fn get_int_value_sync(&self, flag_key: &str) -> EvaluationResult<i64> {
+        match self.resolve_cached_value(flag_key)? {
+            Value::Int(value) => Ok(value),
+            value => Err(type_mismatch(&Type::Int, &value)),
+        }
+    }
+
+    /// Evaluate given `flag_key` as a float (f64) value, synchronously, see
+    /// [`Client::get_bool_value_sync`].
+    pub fn get_float_value_sync(&self, flag_key: &str) -> EvaluationResult<f64> {
+        match self.resolve_cached_value(flag_key)? {
+            Value::Float(value) => Ok(value),
+            value => Err(type_mismatch(&Type::Float, &value)),
+        }
+    }
+
+    /// Evaluate given `flag_key` as a string value, synchronously, see
+    /// [`Client::get_bool_value_sync`].
+    pub fn get_string_value_sync(&self, flag_key: &str) -> EvaluationResult<String> {
+        match self.resolve_cached_value(flag_key)? {
+            Value::String(value) => Ok(value),
+            value => Err(type_mismatch(&Type::String, &value)),
+        }
+    }
+
+    /// Evaluate given `flag_key` as a struct, synchronously, see
+    /// [`Client::get_bool_value_sync`].
+    /// The required type should implement [`From<StructValue>`] trait.
+    pub fn get_struct_value_sync<T: TryFrom<StructValue>>(
+        &self,
+        flag_key: &str,
+    ) -> EvaluationResult<T> {
+        let value = match self.resolve_cached_value(flag_key)? {
+            Value::Struct(value) => value,
+            value => return Err(type_mismatch(&Type::Struct, &value)),
+        };
+
+        T::try_from(value).map_err(|_| EvaluationError {
+            code: EvaluationErrorCode::TypeMismatch,
+            message: Some("Unable to cast value to required type".to_string()),
+        })
+    }
+
+    fn resolve_cached_value(&self, flag_key: &str) -> EvaluationResult<Value> {
+        let provider = self.provider_registry.get(&self.metadata.name);
+        if provider.status() == ProviderStatus::NotReady {
+            return Err(EvaluationError::builder()
+                .code(EvaluationErrorCode::ProviderNotReady)
+                .message("The provider is not ready")
+                .build());
+        }
+
+        let provider = provider.get();
+
+        let details = provider.resolve_cached_value(flag_key).ok_or_else(|| {
+            EvaluationError::builder()
+                .code(EvaluationErrorCode::General(
+                    "Synchronous resolution not supported".to_string(),
+                ))
+                .message(format!(
+                    "Provider {} cannot resolve flags synchronously",
+                    provider.metadata().name
+                ))
+                .build()
+        })??;
+
+        Ok(details.value)
+    }
+}
+
+// ============================================================
+//  Watch
+// ============================================================
+
+#[cfg(feature = "tokio")]
+type EvaluateFn<T> = for<'a> fn(
+    &'a Client,
+    &'a str,
+    Option<&'a EvaluationContext>,
+) -> Pin<
+    Box<dyn Future<Output = EvaluationResult<EvaluationDetails<T>>> + Send + 'a>,
+>;
+
+#[cfg(feature = "tokio")]
+impl Client {
+    /// Watch given `flag_key` evaluated as a bool value with corresponding
+    /// `evaluation_context`.
+    ///
+    /// The flag is evaluated right away, then again every time the bound provider emits a
+    /// configuration change for it, the global evaluation context is replaced, or a provider is
+    /// bound. Errors are reported as `default_value` with the [`EvaluationReason::Error`]
+    /// reason. Watching stops once every receiver is dropped.
+    pub async fn watch_bool(
+        &self,
+        flag_key: &str,
+        evaluation_context: Option<&EvaluationContext>,
+        default_value: bool,
+    ) -> watch::Receiver<EvaluationDetails<bool>> {
+        self.watch(
+            flag_key,
+            evaluation_context,
+            default_value,
+            |client, flag_key, context| Box::pin(client.get_bool_details(flag_key, context, None)),
+        )
+        .await
+    }
+
+    /// Watch given `flag_key` evaluated as an int (i64) value, see [`Client::watch_bool`].
+    pub async fn watch_int(
+        &self,
+        flag_key: &str,
+        evaluation_context: Option<&EvaluationContext>,
+        default_value: i64,
+    ) -> watch::Receiver<EvaluationDetails<i64>> {
+        self.watch(
+            flag_key,
+            evaluation_context,
+            default_value,
+            |client, flag_key, context| Box::pin(client.get_int_details(flag_key, context, None)),
+        )
+        .await
+    }
+
+    /// Watch given `flag_key` evaluated as a float (f64) value, see [`Client::watch_bool`].
+    pub async fn watch_float(
+        &self,
+        flag_key: &str,
+        evaluation_context: Option<&EvaluationContext>,
+        default_value: f64,
+    ) -> watch::Receiver<EvaluationDetails<f64>> {
+        self.watch(
+            flag_key,
+            evaluation_context,
+            default_value,
+            |client, flag_key, context| Box::pin(client.get_float_details(flag_key, context, None)),
+        )
+        .await
+    }
+
+    /// Watch given `flag_key` evaluated as a string value, see [`Client::watch_bool`].
+    pub async fn watch_string(
+        &self,
+        flag_key: &str,
+        evaluation_context: Option<&EvaluationContext>,
+        default_value: impl Into<String>,
+    ) -> watch::Receiver<EvaluationDetails<String>> {
+        self.watch(
+            flag_key,
+            evaluation_context,
+            default_value.into(),
+            |client, flag_key, context| {
+                Box::pin(client.get_string_details(flag_key, context, None))
+            },
+        )
+        .await
+    }
+
+    /// Watch given `flag_key` evaluated as a struct, see [`Client::watch_bool`].
+    pub async fn watch_struct<T>(
+        &self,
+        flag_key: &str,
+        evaluation_context: Option<&EvaluationContext>,
+        default_value: T,
+    ) -> watch::Receiver<EvaluationDetails<T>>
+    where
+        T: TryFrom<StructValue> + Clone + Send + Sync + 'static,
+    {
+        self.watch(
+            flag_key,
+            evaluation_context,
+            default_value,
+            |client, flag_key, context| {
+                Box::pin(client.get_struct_details(flag_key, context, None))
+            },
+        )
+        .await
+    }
+
+    async fn watch<T>(
+        &self,
+        flag_key: &str,
+        evaluation_context: Option<&EvaluationContext>,
+        default_value: T,
+        evaluate: EvaluateFn<T>,
+    ) -> watch::Receiver<EvaluationDetails<T>>
+    where
+        T: Clone + Send + Sync + 'static,
+    {
+        let client = self.clone();
+        let flag_key = flag_key.to_string();
+        let evaluation_context = evaluation_context.cloned();
+
+        // INFO: Subscribe before the first evaluation not to miss any change
+        let mut context_changed = self.global_evaluation_context.subscribe();
+        let mut providers_changed = self.provider_registry.subscribe();
+        let mut provider_events = self.get_provider().subscribe_events();
+
+        let details = client
+            .watched_details(
+                &flag_key,
+                evaluation_context.as_ref(),
+                &default_value,
+                evaluate,
+            )
+            .await;
+        let (sender, receiver) = watch::channel(details);
+
+        tokio::spawn(async move {
+            loop {
+                tokio::select! {
+                    () = sender.closed() => break,
+                    () = context_changed.changed() => {}
+                    () = providers_changed.changed() => {
+                        provider_events = client.get_provider().subscribe_events();
+                    }
+                    () = flag_changed(&mut provider_events, &flag_key) => {}
+                }
+
+                let details = client
+                    .watched_details(
+                        &flag_key,
+                        evaluation_context.as_ref(),
+                        &default_value,
+                        evaluate,
+                    )
+                    .await;
+                sender.send_replace(details);
+            }
+        });
+
+        receiver
+    }
+
+    async fn watched_details<T: Clone>(
+        &self,
+        flag_key: &str,
+        evaluation_context: Option<&EvaluationContext>,
+        default_value: &T,
+        evaluate: EvaluateFn<T>,
+    ) -> EvaluationDetails<T> {
+        evaluate(self, flag_key, evaluation_context)
+            .await
+            .unwrap_or_else(|error| EvaluationDetails {
+                flag_key: flag_key.to_string(),
+                value: default_value.clone(),
+                reason: Some(EvaluationReason::Error),
+                variant: None,
+                flag_metadata: FlagMetadata::default(),
+                error_code: Some(error.code),
+                error_message: error.message,
+            })
+    }
+}
+
+/// Complete once the provider emits an event affecting `flag_key`, or may have done so.
+#[cfg(feature = "tokio")]
+async fn flag_changed(events: &mut Option<Receiver<ProviderEvent>>, flag_key: &str) {
+    if let Some(receiver) = events {
+        loop {
+            match receiver.recv().await {
+                Ok(event) if event.affects(flag_key) => return,
+                Ok(_) => {}
+                Err(RecvError::Overflowed(_)) => return,
+                Err(RecvError::Closed) => break,
+            }
+        }
+    }
+
+    // INFO: No more events to wait for
+    *events = None;
+    std::future::pending::<()>().await;
+}
+
+impl<T: Into<Value>> ResolutionDetails<T> {
+    fn into_value(self) -> ResolutionDetails<Value> {
+        ResolutionDetails {
+            value: self.value.into(),
+            variant: self.variant,
+            reason: self.reason,
+            flag_metadata: self.flag_metadata,
         }
     }
 }
@@ -483,10 +1208,96 @@ impl<T> ResolutionDetails<T> {
             reason: self.reason,
             variant: self.variant,
             flag_metadata: self.flag_metadata.unwrap_or_default(),
+            error_code: None,
+            error_message: None,
+        }
+    }
+}
+
+/// Return the value used as default for flags of type `flag_type` discovered by a bulk
+/// resolution.
+fn default_value(flag_type: &Type) -> Value {
+    match flag_type {
+        Type::Bool => Value::Bool(false),
+        Type::Int => Value::Int(0),
+        Type::Float => Value::Float(0.0),
+        Type::String => Value::String(String::new()),
+        Type::Array => Value::Array(Vec::new()),
+        Type::Struct => Value::Struct(StructValue::default()),
+    }
+}
+
+/// Return the error of a flag resolved as another type than `expected`.
+fn type_mismatch(expected: &Type, resolved: &Value) -> EvaluationError {
+    EvaluationError::builder()
+        .code(EvaluationErrorCode::TypeMismatch)
+        .message(format!(
+            "Expected {expected} but resolved {}",
+            resolved.get_type()
+        ))
+        .build()
+}
+
+/// Pick the flag of `request` out of a bulk resolution.
+fn pick_resolved(
+    resolved: &EvaluationResult<HashMap<String, ResolutionDetails<Value>>>,
+    request: &FlagRequest,
+) -> EvaluationResult<EvaluationDetails<Value>> {
+    let flags = resolved.as_ref().map_err(Clone::clone)?;
+
+    match flags.get(&request.flag_key) {
+        Some(details) if details.value.get_type() == request.flag_type() => {
+            Ok(details.clone().into_evaluation_details(&request.flag_key))
         }
+        Some(details) => Err(type_mismatch(&request.flag_type(), &details.value)),
+        None => Err(EvaluationError::builder()
+            .code(EvaluationErrorCode::FlagNotFound)
+            .message(format!("Flag {} not found", request.flag_key))
+            .build()),
     }
 }
 
+/// Resolve the flag of `request` on its own, with the method matching its type.
+async fn resolve_value(
+    provider: &dyn FeatureProvider,
+    request: &FlagRequest,
+    context: &EvaluationContext,
+) -> EvaluationResult<ResolutionDetails<Value>> {
+    let flag_key = &request.flag_key;
+
+    match request.default_value {
+        Value::Bool(_) => provider
+            .resolve_bool_value(flag_key, context)
+            .await
+            .map(ResolutionDetails::into_value),
+        Value::Int(_) => provider
+            .resolve_int_value(flag_key, context)
+            .await
+            .map(ResolutionDetails::into_value),
+        Value::Float(_) => provider
+            .resolve_float_value(flag_key, context)
+            .await
+            .map(ResolutionDetails::into_value),
+        Value::String(_) => provider
+            .resolve_string_value(flag_key, context)
+            .await
+            .map(ResolutionDetails::into_value),
+        Value::Struct(_) => provider
+            .resolve_struct_value(flag_key, context)
+            .await
+            .map(ResolutionDetails::into_value),
+        Value::Array(_) => Err(array_not_in_bulk()),
+    }
+}
+
+/// The error of an array flag resolved without bulk resolution, which it requires.
+fn array_not_in_bulk() -> EvaluationError {
+    EvaluationError::builder()
+        .code(EvaluationErrorCode::TypeMismatch)
+        .message("Array flags can only be resolved in bulk")
+        .build()
+}
+
 fn call_resolve_bool_value<'a>(
     provider: &'a dyn FeatureProvider,
     flag_key: &'a str,
@@ -534,11 +1345,23 @@ mod tests {
 
     use crate::{
         api::{
+            evaluation_events::EvaluationEvents,
             global_evaluation_context::GlobalEvaluationContext, global_hooks::GlobalHooks,
             provider_registry::ProviderRegistry,
         },
-        provider::{FeatureProvider, MockFeatureProvider, ProviderMetadata, ResolutionDetails},
-        Client, EvaluationReason, FlagMetadata, StructValue, Value,
+        provider::{
+            FeatureProvider, MockFeatureProvider, ProviderMetadata, ProviderStatus,
+            ResolutionDetails,
+        },
+        Client, EvaluationContext, EvaluationError, EvaluationErrorCode, EvaluationOptions,
+        EvaluationReason, FlagMetadata, FlagRequest, FnHook, StructValue, Value,
+    };
+    use std::{
+        collections::HashMap,
+        sync::{
+            atomic::{AtomicUsize, Ordering},
+            Arc,
+        },
     };
 
     #[spec(
@@ -776,12 +1599,72 @@ mod tests {
         number = "1.3.2.1",
         text = "The client MUST provide methods for typed flag evaluation, including boolean, numeric, string, and structure, with parameters flag key (string, required), default value (boolean | number | string | structure, required), and evaluation options (optional), which returns the flag value."
     )]
+    #[tokio::test]
+    async fn get_value_sync() {
+        let mut provider = MockFeatureProvider::new();
+        provider.expect_initialize().returning(|_| {});
+        provider.expect_status().return_const(ProviderStatus::Ready);
+        provider
+            .expect_metadata()
+            .return_const(ProviderMetadata::new("Static"));
+        provider
+            .expect_resolve_cached_value()
+            .returning(|flag_key| match flag_key {
+                "bool" => Some(Ok(ResolutionDetails::new(true))),
+                "string" => Some(Ok(ResolutionDetails::new("Hello"))),
+                _ => Some(Err(EvaluationError::builder()
+                    .code(EvaluationErrorCode::FlagNotFound)
+                    .build())),
+            });
+
+        let client = create_client(provider).await;
+
+        assert_eq!(client.provider_status(), ProviderStatus::Ready);
+        assert_eq!(client.get_bool_value_sync("bool"), Ok(true));
+        assert_eq!(
+            client.get_string_value_sync("string"),
+            Ok("Hello".to_string())
+        );
+        assert_eq!(
+            client.get_int_value_sync("bool").unwrap_err().code,
+            EvaluationErrorCode::TypeMismatch
+        );
+        assert_eq!(
+            client.get_float_value_sync("missing").unwrap_err().code,
+            EvaluationErrorCode::FlagNotFound
+        );
+    }
+
+    #[tokio::test]
+    async fn get_value_sync_without_cached_state() {
+        let mut provider = MockFeatureProvider::new();
+        provider.expect_initialize().returning(|_| {});
+        provider.expect_status().return_const(ProviderStatus::Ready);
+        provider
+            .expect_metadata()
+            .return_const(ProviderMetadata::new("Dynamic"));
+        provider.expect_resolve_cached_value().returning(|_| None);
+
+        let client = create_client(provider).await;
+        assert_eq!(
+            client.get_bool_value_sync("flag").unwrap_err().code,
+            EvaluationErrorCode::General("Synchronous resolution not supported".to_string())
+        );
+
+        let client = create_default_client();
+        assert_eq!(client.provider_status(), ProviderStatus::NotReady);
+        assert_eq!(
+            client.get_bool_value_sync("flag").unwrap_err().code,
+            EvaluationErrorCode::ProviderNotReady
+        );
+    }
+
     #[spec(
         number = "1.4.2.1",
         text = "The client MUST provide methods for detailed flag value evaluation with parameters flag key (string, required), default value (boolean | number | string | structure, required), and evaluation options (optional), which returns an evaluation details structure."
     )]
     #[test]
-    fn static_context_not_applicable() {}
+    fn static_context_details_not_applicable() {}
 
     #[tokio::test]
     async fn with_hook() {
@@ -807,11 +1690,378 @@ mod tests {
         assert_eq!(client.client_hooks.len(), 1);
     }
 
+    #[test]
+    fn client_is_shareable() {
+        fn assert_shareable<T: Clone + Send + Sync + 'static>() {}
+
+        assert_shareable::<Client>();
+    }
+
+    #[tokio::test]
+    async fn with_context() {
+        let mut provider = MockFeatureProvider::new();
+        provider.expect_initialize().returning(|_| {});
+        provider.expect_hooks().return_const(vec![]);
+        provider
+            .expect_metadata()
+            .return_const(ProviderMetadata::default());
+        provider
+            .expect_resolve_string_value()
+            .returning(|_, context| {
+                Ok(ResolutionDetails::new(
+                    context.custom_fields["plan"].as_str().unwrap(),
+                ))
+            });
+
+        let mut client = create_client(provider).await;
+        client.set_evaluation_context(
+            EvaluationContext::default()
+                .with_targeting_key("user-1")
+                .with_custom_field("plan", "free"),
+        );
+
+        let scoped = client.with_context(
+            EvaluationContext::default()
+                .with_custom_field("plan", "pro")
+                .with_custom_field("request_id", "42"),
+        );
+
+        assert_eq!(
+            *scoped.evaluation_context,
+            EvaluationContext::default()
+                .with_targeting_key("user-1")
+                .with_custom_field("plan", "pro")
+                .with_custom_field("request_id", "42")
+        );
+        assert_eq!(
+            scoped.get_string_value("plan", None, None).await,
+            Ok("pro".to_string())
+        );
+        assert_eq!(
+            client.get_string_value("plan", None, None).await,
+            Ok("free".to_string())
+        );
+    }
+
+    #[tokio::test]
+    async fn with_hooks() {
+        let client = create_default_client().with_hook(crate::LoggingHook::default());
+
+        let scoped = client.with_hooks([counting_hook().0, counting_hook().0]);
+
+        assert_eq!(client.client_hooks.len(), 1);
+        assert_eq!(scoped.client_hooks.len(), 3);
+    }
+
+    fn bulk_provider() -> MockFeatureProvider {
+        let mut provider = MockFeatureProvider::new();
+        provider.expect_initialize().returning(|_| {});
+        provider.expect_hooks().return_const(vec![]);
+        provider
+            .expect_metadata()
+            .return_const(ProviderMetadata::default());
+        provider
+    }
+
+    fn bulk_flags() -> HashMap<String, ResolutionDetails<Value>> {
+        HashMap::from([
+            (
+                "bool".to_string(),
+                ResolutionDetails::builder()
+                    .value(Value::Bool(true))
+                    .variant("on")
+                    .build(),
+            ),
+            ("int".to_string(), ResolutionDetails::new(3)),
+        ])
+    }
+
+    fn counting_hook() -> (FnHook, Arc<AtomicUsize>) {
+        let count = Arc::new(AtomicUsize::new(0));
+        let hook = {
+            let count = count.clone();
+            FnHook::finally_fn(move |_, _, _| {
+                count.fetch_add(1, Ordering::SeqCst);
+            })
+        };
+
+        (hook, count)
+    }
+
+    #[tokio::test]
+    async fn evaluate_many_in_bulk() {
+        let mut provider = bulk_provider();
+        provider
+            .expect_resolve_all()
+            .return_const(Some(Ok(bulk_flags())))
+            .once();
+
+        let (hook, finally_count) = counting_hook();
+        let client = create_client(provider).await.with_hook(hook);
+
+        let results = client
+            .evaluate_many(
+                &[
+                    FlagRequest::new("bool", false),
+                    FlagRequest::new("int", 0),
+                    FlagRequest::new("missing", "default"),
+                    FlagRequest::new("int", false),
+                ],
+                None,
+                None,
+            )
+            .await;
+
+        assert_eq!(results.len(), 4);
+        let details = results[0].as_ref().unwrap();
+        assert_eq!(details.value, Value::Bool(true));
+        assert_eq!(details.variant.as_deref(), Some("on"));
+        assert_eq!(results[1].as_ref().unwrap().value, Value::Int(3));
+        assert_eq!(
+            results[2].as_ref().unwrap_err().code,
+            EvaluationErrorCode::FlagNotFound
+        );
+        assert_eq!(
+            results[3].as_ref().unwrap_err().code,
+            EvaluationErrorCode::TypeMismatch
+        );
+        assert_eq!(finally_count.load(Ordering::SeqCst), 4);
+    }
+
+    #[tokio::test]
+    async fn evaluate_many_without_bulk_support() {
+        let mut provider = bulk_provider();
+        provider.expect_resolve_all().return_const(None).once();
+        provider
+            .expect_resolve_bool_value()
+            .return_const(Ok(ResolutionDetails::new(true)));
+        provider
+            .expect_resolve_string_value()
+            .return_const(Ok(ResolutionDetails::new("value".to_string())));
+
+        let client = create_client(provider).await;
+
+        let results = client
+            .evaluate_many(
+                &[
+                    FlagRequest::new("bool", false),
+                    FlagRequest::new("string", ""),
+                ],
+                None,
+                None,
+            )
+            .await;
+
+        assert_eq!(results[0].as_ref().unwrap().value, Value::Bool(true));
+        assert_eq!(
+            results[1].as_ref().unwrap().value,
+            Value::String("value".to_string())
+        );
+    }
+
+    #[tokio::test]
+    async fn evaluate_many_with_context_changed_by_hook() {
+        let mut provider = bulk_provider();
+        provider
+            .expect_resolve_all()
+            .withf(|context| context.targeting_key.is_none())
+            .return_const(Some(Ok(bulk_flags())))
+            .once();
+        provider
+            .expect_resolve_int_value()
+            .withf(|flag_key, context| {
+                flag_key == "int" && context.targeting_key.as_deref() == Some("hook")
+            })
+            .return_const(Ok(ResolutionDetails::new(42)))
+            .once();
+
+        let client = create_client(provider).await;
+
+        let hook = FnHook::before_fn(|context, _| {
+            Ok((context.flag_key == "int")
+                .then(|| EvaluationContext::default().with_targeting_key("hook")))
+        });
+        let options = EvaluationOptions::default().with_hook(hook);
+
+        let results = client
+            .evaluate_many(
+                &[FlagRequest::new("bool", false), FlagRequest::new("int", 0)],
+                None,
+                Some(&options),
+            )
+            .await;
+
+        assert_eq!(results[0].as_ref().unwrap().value, Value::Bool(true));
+        assert_eq!(results[1].as_ref().unwrap().value, Value::Int(42));
+    }
+
+    #[tokio::test]
+    async fn evaluate_array_with_context_changed_by_hook() {
+        let mut provider = bulk_provider();
+        provider
+            .expect_resolve_all()
+            .withf(|context| context.targeting_key.is_none())
+            .return_const(Some(Ok(bulk_flags())))
+            .once();
+        provider
+            .expect_resolve_all()
+            .withf(|context| context.targeting_key.as_deref() == Some("hook"))
+            .return_const(Some(Ok(HashMap::from([
+                (
+                    "array".to_string(),
+                    ResolutionDetails::new(Value::Array(vec![Value::Int(1)])),
+                ),
+                (
+                    "other".to_string(),
+                    ResolutionDetails::new(Value::Array(vec![])),
+                ),
+            ]))))
+            .once();
+
+        let client = create_client(provider).await;
+
+        let hook = FnHook::before_fn(|context, _| {
+            Ok((context.flag_key != "bool")
+                .then(|| EvaluationContext::default().with_targeting_key("hook")))
+        });
+        let options = EvaluationOptions::default().with_hook(hook);
+
+        let results = client
+            .evaluate_many(
+                &[
+                    FlagRequest::new("bool", false),
+                    FlagRequest::new("array", Value::Array(vec![])),
+                    FlagRequest::new("other", Value::Array(vec![])),
+                ],
+                None,
+                Some(&options),
+            )
+            .await;
+
+        assert_eq!(results[0].as_ref().unwrap().value, Value::Bool(true));
+        assert_eq!(
+            results[1].as_ref().unwrap().value,
+            Value::Array(vec![Value::Int(1)])
+        );
+        assert_eq!(results[2].as_ref().unwrap().value, Value::Array(vec![]));
+    }
+
+    #[tokio::test]
+    async fn evaluate_all() {
+        let mut provider = bulk_provider();
+        provider
+            .expect_resolve_all()
+            .return_const(Some(Ok(bulk_flags())))
+            .once();
+
+        let (hook, finally_count) = counting_hook();
+        let client = create_client(provider).await.with_hook(hook);
+
+        let results = client.evaluate_all(None, None).await.unwrap();
+
+        assert_eq!(results.len(), 2);
+        assert_eq!(results["bool"].as_ref().unwrap().value, Value::Bool(true));
+        assert_eq!(results["int"].as_ref().unwrap().value, Value::Int(3));
+        assert_eq!(finally_count.load(Ordering::SeqCst), 2);
+    }
+
+    #[tokio::test]
+    async fn evaluate_all_without_bulk_support() {
+        let mut provider = bulk_provider();
+        provider.expect_resolve_all().return_const(None);
+
+        let client = create_client(provider).await;
+
+        assert!(matches!(
+            client.evaluate_all(None, None).await.unwrap_err().code,
+            EvaluationErrorCode::General(_)
+        ));
+    }
+
+    #[cfg(feature = "tokio")]
+    #[tokio::test]
+    async fn watch_on_configuration_changed() {
+        use crate::provider::ProviderEvent;
+
+        let (events, receiver) = crate::async_broadcast::broadcast(8);
+        let _events = receiver.deactivate();
+        let resolutions = Arc::new(AtomicUsize::new(0));
+
+        let mut provider = bulk_provider();
+        provider.expect_subscribe_events().returning({
+            let events = events.clone();
+            move || Some(events.new_receiver())
+        });
+        provider.expect_resolve_int_value().returning({
+            let resolutions = resolutions.clone();
+            move |_, _| {
+                let count = resolutions.fetch_add(1, Ordering::SeqCst);
+                Ok(ResolutionDetails::new(i64::try_from(count).unwrap()))
+            }
+        });
+
+        let mut api = crate::OpenFeature::default();
+        api.set_provider(provider).await;
+        let client = api.create_client();
+
+        let mut receiver = client.watch_int("flag", None, -1).await;
+        assert_eq!(receiver.borrow_and_update().value, 0);
+
+        events
+            .try_broadcast(ProviderEvent::ConfigurationChanged {
+                flags_changed: Some(vec!["other".to_string()]),
+            })
+            .unwrap();
+        events
+            .try_broadcast(ProviderEvent::ConfigurationChanged {
+                flags_changed: Some(vec!["flag".to_string()]),
+            })
+            .unwrap();
+
+        receiver.changed().await.unwrap();
+        assert_eq!(receiver.borrow_and_update().value, 1);
+        assert_eq!(resolutions.load(Ordering::SeqCst), 2);
+    }
+
+    #[cfg(feature = "tokio")]
+    #[tokio::test]
+    async fn watch_on_global_context_changed() {
+        let mut provider = bulk_provider();
+        provider.expect_subscribe_events().returning(|| None);
+        provider.expect_on_context_changed().return_const(());
+        provider
+            .expect_resolve_string_value()
+            .returning(|_, context| match &context.targeting_key {
+                Some(targeting_key) => Ok(ResolutionDetails::new(targeting_key.clone())),
+                None => Err(EvaluationError::builder()
+                    .code(EvaluationErrorCode::TargetingKeyMissing)
+                    .build()),
+            });
+
+        let mut api = crate::OpenFeature::default();
+        api.set_provider(provider).await;
+        let client = api.create_client();
+
+        let mut receiver = client.watch_string("flag", None, "default").await;
+        {
+            let details = receiver.borrow_and_update();
+            assert_eq!(details.value, "default");
+            assert_eq!(details.reason, Some(EvaluationReason::Error));
+        }
+
+        api.set_evaluation_context(EvaluationContext::default().with_targeting_key("user-1"))
+            .await;
+
+        receiver.changed().await.unwrap();
+        assert_eq!(receiver.borrow_and_update().value, "user-1");
+    }
+
     fn create_default_client() -> Client {
         Client::new(
             "no_op",
             GlobalEvaluationContext::default(),
             GlobalHooks::default(),
+            EvaluationEvents::default(),
             ProviderRegistry::default(),
         )
     }
@@ -824,6 +2074,7 @@ mod tests {
             "custom",
             GlobalEvaluationContext::default(),
             GlobalHooks::default(),
+            EvaluationEvents::default(),
             provider_registry,
         )
     }
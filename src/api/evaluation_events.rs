@@ -0,0 +1,526 @@
+use std::{
+    collections::HashMap,
+    hash::{BuildHasher, RandomState},
+    pin::Pin,
+    sync::{
+        atomic::{AtomicU64, AtomicUsize, Ordering},
+        Arc, Mutex, PoisonError,
+    },
+    task::{ready, Context, Poll},
+    time::{Duration, Instant},
+};
+
+use arc_swap::ArcSwap;
+use async_broadcast::{InactiveReceiver, Receiver, RecvError, Sender};
+use futures_core::Stream;
+use time::OffsetDateTime;
+
+use crate::{EvaluationDetails, EvaluationReason, HookContext, Redaction, Value};
+
+/// The number of independently locked shards of the dedupe state.
+const DEDUPE_SHARDS: usize = 16;
+
+/// The maximum number of exposures remembered in each of the two generations of a dedupe shard.
+const DEDUPE_CAPACITY: usize = 256;
+
+// ============================================================
+//  EvaluationEvent
+// ============================================================
+
+/// A completed flag evaluation, published to the subscribers of
+/// [`OpenFeature::subscribe_evaluation_events`](crate::OpenFeature::subscribe_evaluation_events).
+#[derive(Clone, PartialEq, Debug)]
+pub struct EvaluationEvent {
+    /// The key of the evaluated flag.
+    pub flag_key: String,
+
+    /// The domain of the client.
+    pub domain: String,
+
+    /// The name of the provider that resolved the flag.
+    pub provider_name: String,
+
+    /// The targeting key of the evaluation context, after the before hooks ran. It is redacted
+    /// if marked as private.
+    pub targeting_key: Option<String>,
+
+    /// The resolved variant.
+    pub variant: Option<String>,
+
+    /// The reason of the resolution.
+    pub reason: Option<EvaluationReason>,
+
+    /// When the evaluation completed.
+    pub timestamp: OffsetDateTime,
+}
+
+// ============================================================
+//  EvaluationEventOptions
+// ============================================================
+
+/// The configuration of the evaluation event stream.
+#[derive(Clone, PartialEq, Eq, Debug)]
+pub struct EvaluationEventOptions {
+    /// The number of events buffered for each subscriber. A subscriber falling further behind
+    /// misses the oldest events, which are counted as dropped.
+    pub capacity: usize,
+
+    /// When set, an evaluation with the same targeting key, flag key and variant as one
+    /// published less than this duration ago is not published again.
+    pub dedupe_window: Option<Duration>,
+
+    /// How a targeting key marked as private is redacted. Masked by default.
+    pub redaction: Redaction,
+}
+
+impl Default for EvaluationEventOptions {
+    fn default() -> Self {
+        Self {
+            capacity: 1024,
+            dedupe_window: None,
+            redaction: Redaction::default(),
+        }
+    }
+}
+
+impl EvaluationEventOptions {
+    /// Set the number of events buffered for each subscriber.
+    #[must_use]
+    pub fn with_capacity(mut self, capacity: usize) -> Self {
+        self.capacity = capacity;
+        self
+    }
+
+    /// Publish repeated exposures of the same (user, flag, variant) once per `window`.
+    #[must_use]
+    pub fn with_dedupe_window(mut self, window: Duration) -> Self {
+        self.dedupe_window = Some(window);
+        self
+    }
+
+    /// Set how a targeting key marked as private is redacted.
+    #[must_use]
+    pub fn with_redaction(mut self, redaction: Redaction) -> Self {
+        self.redaction = redaction;
+        self
+    }
+}
+
+// ============================================================
+//  EvaluationEventStats
+// ============================================================
+
+/// Counters of the evaluation event stream.
+#[derive(Clone, Copy, Default, PartialEq, Eq, Debug)]
+pub struct EvaluationEventStats {
+    /// The number of events published while at least one subscriber was listening.
+    pub published: u64,
+
+    /// The number of events missed by lagging subscribers, summed over all of them.
+    pub dropped: u64,
+
+    /// The number of events not published because of the dedupe window.
+    pub deduplicated: u64,
+}
+
+#[derive(Default)]
+struct Counters {
+    published: AtomicU64,
+    dropped: AtomicU64,
+    deduplicated: AtomicU64,
+    subscribers: AtomicUsize,
+}
+
+// ============================================================
+//  EvaluationEventStream
+// ============================================================
+
+/// A subscription to the evaluation events, as an async [`Stream`].
+///
+/// The stream ends when the event stream is reconfigured through
+/// [`OpenFeature::set_evaluation_event_options`](crate::OpenFeature::set_evaluation_event_options).
+pub struct EvaluationEventStream {
+    inner: Receiver<EvaluationEvent>,
+    counters: Arc<Counters>,
+    dropped: u64,
+}
+
+impl EvaluationEventStream {
+    /// Return the number of events this subscriber missed because it was lagging behind.
+    pub fn dropped(&self) -> u64 {
+        self.dropped
+    }
+}
+
+impl Drop for EvaluationEventStream {
+    fn drop(&mut self) {
+        self.counters.subscribers.fetch_sub(1, Ordering::Relaxed);
+    }
+}
+
+impl Stream for EvaluationEventStream {
+    type Item = EvaluationEvent;
+
+    fn poll_next(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        loop {
+            match ready!(Pin::new(&mut self.inner).poll_recv(cx)) {
+                Some(Ok(event)) => return Poll::Ready(Some(event)),
+                Some(Err(RecvError::Overflowed(count))) => {
+                    self.dropped += count;
+                    self.counters.dropped.fetch_add(count, Ordering::Relaxed);
+                }
+                Some(Err(RecvError::Closed)) | None => return Poll::Ready(None),
+            }
+        }
+    }
+}
+
+// ============================================================
+//  EvaluationEvents
+// ============================================================
+
+type DedupeKey = (Option<String>, String, Option<String>);
+
+/// The exposures published within the dedupe window, in two generations swapped once per window,
+/// so expired exposures are dropped with the old generation instead of being scanned for.
+struct Dedupe {
+    window: Duration,
+    current: HashMap<DedupeKey, Instant>,
+    previous: HashMap<DedupeKey, Instant>,
+    rotated_at: Instant,
+}
+
+impl Dedupe {
+    fn new(window: Duration) -> Self {
+        Self {
+            window,
+            current: HashMap::new(),
+            previous: HashMap::new(),
+            rotated_at: Instant::now(),
+        }
+    }
+
+    /// Return `true` if `key` was published within the window, remembering it otherwise.
+    fn is_duplicate(&mut self, key: DedupeKey, now: Instant) -> bool {
+        // INFO: A full generation rotates early, at worst publishing a duplicate exposure
+        if now.duration_since(self.rotated_at) >= self.window
+            || self.current.len() >= DEDUPE_CAPACITY
+        {
+            self.previous = std::mem::take(&mut self.current);
+            self.rotated_at = now;
+        }
+
+        let is_recent = |published_at: &HashMap<DedupeKey, Instant>| {
+            published_at
+                .get(&key)
+                .is_some_and(|published_at| now.duration_since(*published_at) < self.window)
+        };
+
+        if is_recent(&self.current) || is_recent(&self.previous) {
+            return true;
+        }
+
+        self.current.insert(key, now);
+        false
+    }
+}
+
+/// The exposures published within the dedupe window, sharded by key so that concurrent
+/// evaluations seldom contend.
+struct ShardedDedupe {
+    hasher: RandomState,
+    shards: Vec<Mutex<Dedupe>>,
+}
+
+impl ShardedDedupe {
+    fn new(window: Duration) -> Self {
+        Self {
+            hasher: RandomState::new(),
+            shards: (0..DEDUPE_SHARDS)
+                .map(|_| Mutex::new(Dedupe::new(window)))
+                .collect(),
+        }
+    }
+
+    fn is_duplicate(&self, key: DedupeKey, now: Instant) -> bool {
+        #[allow(clippy::cast_possible_truncation)]
+        let shard = &self.shards[self.hasher.hash_one(&key) as usize % DEDUPE_SHARDS];
+
+        // INFO: A panic while holding the shard leaves its maps usable
+        shard
+            .lock()
+            .unwrap_or_else(PoisonError::into_inner)
+            .is_duplicate(key, now)
+    }
+}
+
+/// The channel of the events and its dedupe state, replaced as a whole when reconfigured.
+struct Channel {
+    sender: Sender<EvaluationEvent>,
+    // INFO: Kept to leave the channel open while nobody is subscribed
+    inactive: InactiveReceiver<EvaluationEvent>,
+    dedupe: Option<ShardedDedupe>,
+    redaction: Redaction,
+}
+
+impl Channel {
+    /// Create a channel dropping the oldest events of a full buffer rather than waiting for the
+    /// lagging subscribers.
+    fn new(options: EvaluationEventOptions) -> Self {
+        let (mut sender, receiver) = async_broadcast::broadcast(options.capacity.max(1));
+        sender.set_overflow(true);
+        sender.set_await_active(false);
+
+        Self {
+            sender,
+            inactive: receiver.deactivate(),
+            dedupe: options.dedupe_window.map(ShardedDedupe::new),
+            redaction: options.redaction,
+        }
+    }
+}
+
+/// The publishing side of the evaluation event stream, shared by the API and its clients.
+///
+/// Evaluations publish without a global lock: the channel is read from a snapshot, and only the
+/// dedupe shard of the exposure is locked.
+#[derive(Clone)]
+pub struct EvaluationEvents {
+    channel: Arc<ArcSwap<Channel>>,
+    counters: Arc<Counters>,
+}
+
+impl Default for EvaluationEvents {
+    fn default() -> Self {
+        Self {
+            channel: Arc::new(ArcSwap::from_pointee(Channel::new(
+                EvaluationEventOptions::default(),
+            ))),
+            counters: Arc::default(),
+        }
+    }
+}
+
+impl EvaluationEvents {
+    pub fn configure(&self, options: EvaluationEventOptions) {
+        self.channel.store(Arc::new(Channel::new(options)));
+    }
+
+    pub fn subscribe(&self) -> EvaluationEventStream {
+        self.counters.subscribers.fetch_add(1, Ordering::Relaxed);
+
+        EvaluationEventStream {
+            inner: self.channel.load().inactive.activate_cloned(),
+            counters: self.counters.clone(),
+            dropped: 0,
+        }
+    }
+
+    pub fn stats(&self) -> EvaluationEventStats {
+        EvaluationEventStats {
+            published: self.counters.published.load(Ordering::Relaxed),
+            dropped: self.counters.dropped.load(Ordering::Relaxed),
+            deduplicated: self.counters.deduplicated.load(Ordering::Relaxed),
+        }
+    }
+
+    pub fn publish(&self, context: &HookContext<'_>, details: &EvaluationDetails<Value>) {
+        // INFO: Nothing to build when nobody listens
+        if self.counters.subscribers.load(Ordering::Relaxed) == 0 {
+            return;
+        }
+
+        let channel = self.channel.load();
+
+        // INFO: The remaining subscribers may all be on a replaced channel
+        if channel.sender.receiver_count() == 0 {
+            return;
+        }
+
+        if let Some(dedupe) = &channel.dedupe {
+            let key = (
+                context.evaluation_context.targeting_key.clone(),
+                context.flag_key.to_string(),
+                details.variant.clone(),
+            );
+
+            if dedupe.is_duplicate(key, Instant::now()) {
+                self.counters.deduplicated.fetch_add(1, Ordering::Relaxed);
+                return;
+            }
+        }
+
+        let event = EvaluationEvent {
+            flag_key: context.flag_key.to_string(),
+            domain: context.client_metadata.name.clone(),
+            provider_name: context.provider_metadata.name.clone(),
+            targeting_key: context
+                .evaluation_context
+                .redacted_targeting_key(&channel.redaction),
+            variant: details.variant.clone(),
+            reason: details.reason.clone(),
+            timestamp: OffsetDateTime::now_utc(),
+        };
+
+        if channel.sender.try_broadcast(event).is_ok() {
+            self.counters.published.fetch_add(1, Ordering::Relaxed);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use tokio_stream::StreamExt;
+
+    use crate::{
+        provider::{MockFeatureProvider, ProviderMetadata, ResolutionDetails},
+        EvaluationContext, OpenFeature,
+    };
+
+    use super::*;
+
+    async fn create_api() -> OpenFeature {
+        let mut provider = MockFeatureProvider::new();
+        provider.expect_initialize().returning(|_| {});
+        provider.expect_hooks().return_const(vec![]);
+        provider
+            .expect_metadata()
+            .return_const(ProviderMetadata::new("Test Provider"));
+        provider.expect_resolve_bool_value().returning(|_, _| {
+            Ok(ResolutionDetails::builder()
+                .value(true)
+                .variant("on")
+                .reason(EvaluationReason::TargetingMatch)
+                .build())
+        });
+
+        let mut api = OpenFeature::default();
+        api.set_provider(provider).await;
+        api
+    }
+
+    #[tokio::test]
+    async fn publish_evaluations() {
+        let api = create_api().await;
+        let mut events = api.subscribe_evaluation_events();
+        let client = api.create_named_client("domain");
+
+        let context = EvaluationContext::default().with_targeting_key("user-1");
+        assert_eq!(
+            client.get_bool_value("flag", Some(&context), None).await,
+            Ok(true)
+        );
+
+        let event = events.next().await.unwrap();
+        assert_eq!(event.flag_key, "flag");
+        assert_eq!(event.domain, "domain");
+        assert_eq!(event.provider_name, "Test Provider");
+        assert_eq!(event.targeting_key.as_deref(), Some("user-1"));
+        assert_eq!(event.variant.as_deref(), Some("on"));
+        assert_eq!(event.reason, Some(EvaluationReason::TargetingMatch));
+        assert_eq!(api.evaluation_event_stats().published, 1);
+    }
+
+    #[tokio::test]
+    async fn redact_private_targeting_key() {
+        let mut api = create_api().await;
+        api.set_evaluation_event_options(
+            EvaluationEventOptions::default().with_redaction(Redaction::hash("salt")),
+        );
+        let mut events = api.subscribe_evaluation_events();
+        let client = api.create_client();
+
+        let context = EvaluationContext::default().with_private_targeting_key("user-1");
+        let _ = client.get_bool_value("flag", Some(&context), None).await;
+
+        assert_eq!(
+            events.next().await.unwrap().targeting_key,
+            Some(Redaction::hash("salt").apply_str("user-1"))
+        );
+    }
+
+    #[tokio::test]
+    async fn count_dropped_events() {
+        let mut api = create_api().await;
+        api.set_evaluation_event_options(EvaluationEventOptions::default().with_capacity(2));
+        let mut events = api.subscribe_evaluation_events();
+        let client = api.create_client();
+
+        for _ in 0..5 {
+            let _ = client.get_bool_value("flag", None, None).await;
+        }
+
+        assert_eq!(events.next().await.unwrap().flag_key, "flag");
+        assert_eq!(events.next().await.unwrap().flag_key, "flag");
+        assert_eq!(events.dropped(), 3);
+        assert_eq!(
+            api.evaluation_event_stats(),
+            EvaluationEventStats {
+                published: 5,
+                dropped: 3,
+                deduplicated: 0,
+            }
+        );
+    }
+
+    #[tokio::test]
+    async fn dedupe_exposures() {
+        let mut api = create_api().await;
+        api.set_evaluation_event_options(
+            EvaluationEventOptions::default().with_dedupe_window(Duration::from_secs(60)),
+        );
+        let events = api.subscribe_evaluation_events();
+        let client = api.create_client();
+
+        let user_1 = EvaluationContext::default().with_targeting_key("user-1");
+        let user_2 = EvaluationContext::default().with_targeting_key("user-2");
+        for context in [&user_1, &user_1, &user_2, &user_1] {
+            let _ = client.get_bool_value("flag", Some(context), None).await;
+        }
+        drop(api);
+        drop(client);
+
+        let targeting_keys: Vec<_> = events.map(|event| event.targeting_key).collect().await;
+        assert_eq!(
+            targeting_keys,
+            [Some("user-1".to_string()), Some("user-2".to_string())]
+        );
+    }
+
+    #[tokio::test]
+    async fn skip_without_subscribers() {
+        let api = create_api().await;
+        let client = api.create_client();
+
+        let _ = client.get_bool_value("flag", None, None).await;
+
+        assert_eq!(
+            api.evaluation_event_stats(),
+            EvaluationEventStats::default()
+        );
+    }
+
+    #[test]
+    fn bound_dedupe_state() {
+        let window = Duration::from_secs(60);
+        let mut dedupe = Dedupe::new(window);
+        let now = Instant::now();
+        let key = |index: usize| (None, format!("flag-{index}"), None);
+
+        assert!(!dedupe.is_duplicate(key(0), now));
+        assert!(dedupe.is_duplicate(key(0), now + window / 2));
+
+        // The previous generation still dedupes within the window...
+        assert!(!dedupe.is_duplicate(key(1), now + window));
+        assert!(dedupe.is_duplicate(key(1), now + window * 3 / 2));
+        // ...and is dropped as a whole once it expired.
+        assert!(!dedupe.is_duplicate(key(0), now + window * 2));
+        assert!(dedupe.previous.len() <= 1);
+
+        for index in 0..DEDUPE_CAPACITY * 3 {
+            dedupe.is_duplicate(key(index), now + window * 2);
+        }
+        assert!(dedupe.current.len() <= DEDUPE_CAPACITY);
+        assert!(dedupe.previous.len() <= DEDUPE_CAPACITY);
+    }
+}
@@ -0,0 +1,305 @@
+use async_trait::async_trait;
+
+use crate::{
+    Client, EvaluationContext, EvaluationDetails, EvaluationOptions, EvaluationResult,
+    FlagMetadata, HookWrapper, StructValue, Value,
+};
+
+// ============================================================
+//  Flag
+// ============================================================
+
+/// A typed flag, declared once with its key and default value, and evaluated everywhere through
+/// [`Client::get`] and [`Client::details`].
+///
+/// ```
+/// use open_feature::{Flag, OpenFeature};
+///
+/// const NEW_CHECKOUT: Flag<bool> = Flag::new("new-checkout", false);
+/// const CHECKOUT_TITLE: Flag<&str> = Flag::new("checkout-title", "Checkout");
+///
+/// # async fn example() {
+/// let client = OpenFeature::singleton().await.create_client();
+///
+/// if client.get(&NEW_CHECKOUT, None).await {
+///     let title: String = client.get(&CHECKOUT_TITLE, None).await;
+/// }
+/// # }
+/// ```
+///
+/// Flags with hooks or metadata are built at runtime, e.g. in a [`LazyLock`](std::sync::LazyLock).
+#[derive(Clone)]
+pub struct Flag<T> {
+    key: &'static str,
+    default_value: T,
+    hooks: Vec<HookWrapper>,
+    metadata: Option<FlagMetadata>,
+}
+
+impl<T> Flag<T> {
+    /// Declare the flag `key`, evaluated as `default_value` when the evaluation fails.
+    pub const fn new(key: &'static str, default_value: T) -> Self {
+        Self {
+            key,
+            default_value,
+            hooks: Vec::new(),
+            metadata: None,
+        }
+    }
+
+    /// Add a hook run on every evaluation of this flag, after the client hooks.
+    #[must_use]
+    pub fn with_hook<H: Into<HookWrapper>>(mut self, hook: H) -> Self {
+        self.hooks.push(hook.into());
+        self
+    }
+
+    /// Set the metadata of the flag, completing the metadata returned by the provider.
+    #[must_use]
+    pub fn with_metadata(mut self, metadata: FlagMetadata) -> Self {
+        self.metadata = Some(metadata);
+        self
+    }
+
+    /// Return the key of the flag.
+    pub fn key(&self) -> &'static str {
+        self.key
+    }
+
+    /// Return the default value of the flag.
+    pub fn default_value(&self) -> &T {
+        &self.default_value
+    }
+
+    /// Return the hooks of the flag.
+    pub fn hooks(&self) -> &[HookWrapper] {
+        &self.hooks
+    }
+
+    /// Return the metadata of the flag.
+    pub fn metadata(&self) -> Option<&FlagMetadata> {
+        self.metadata.as_ref()
+    }
+}
+
+// ============================================================
+//  FlagType
+// ============================================================
+
+/// The types a [`Flag`] can be declared with.
+///
+/// Implement it to declare flags of a struct type, evaluated through
+/// [`Client::get_struct_details`].
+#[async_trait]
+pub trait FlagType: Send + Sync {
+    /// The type the flag evaluates to, e.g. [`String`] for `&'static str`.
+    type Value: Send;
+
+    /// Return the evaluated form of the default value.
+    fn to_value(&self) -> Self::Value;
+
+    /// Return the default value as reported to the hooks and the evaluation events.
+    /// `None` by default, where the zero value of the evaluated type is reported instead.
+    fn to_default_value(&self) -> Option<Value> {
+        None
+    }
+
+    /// Evaluate `flag_key` with the typed evaluation method of `client`.
+    async fn evaluate(
+        client: &Client,
+        flag_key: &str,
+        evaluation_context: Option<&EvaluationContext>,
+        evaluation_options: Option<&EvaluationOptions>,
+    ) -> EvaluationResult<EvaluationDetails<Self::Value>>;
+}
+
+macro_rules! impl_flag_type {
+    ($type:ty, $value:ty, $method:ident) => {
+        #[async_trait]
+        impl FlagType for $type {
+            type Value = $value;
+
+            fn to_value(&self) -> Self::Value {
+                (*self).into()
+            }
+
+            fn to_default_value(&self) -> Option<Value> {
+                Some((*self).into())
+            }
+
+            async fn evaluate(
+                client: &Client,
+                flag_key: &str,
+                evaluation_context: Option<&EvaluationContext>,
+                evaluation_options: Option<&EvaluationOptions>,
+            ) -> EvaluationResult<EvaluationDetails<Self::Value>> {
+                client
+                    .$method(flag_key, evaluation_context, evaluation_options)
+                    .await
+            }
+        }
+    };
+}
+
+impl_flag_type!(bool, bool, get_bool_details);
+impl_flag_type!(i64, i64, get_int_details);
+impl_flag_type!(f64, f64, get_float_details);
+impl_flag_type!(&'static str, String, get_string_details);
+
+#[async_trait]
+impl FlagType for String {
+    type Value = String;
+
+    fn to_value(&self) -> Self::Value {
+        self.clone()
+    }
+
+    fn to_default_value(&self) -> Option<Value> {
+        Some(self.clone().into())
+    }
+
+    async fn evaluate(
+        client: &Client,
+        flag_key: &str,
+        evaluation_context: Option<&EvaluationContext>,
+        evaluation_options: Option<&EvaluationOptions>,
+    ) -> EvaluationResult<EvaluationDetails<Self::Value>> {
+        client
+            .get_string_details(flag_key, evaluation_context, evaluation_options)
+            .await
+    }
+}
+
+#[async_trait]
+impl FlagType for StructValue {
+    type Value = StructValue;
+
+    fn to_value(&self) -> Self::Value {
+        self.clone()
+    }
+
+    fn to_default_value(&self) -> Option<Value> {
+        Some(self.clone().into())
+    }
+
+    async fn evaluate(
+        client: &Client,
+        flag_key: &str,
+        evaluation_context: Option<&EvaluationContext>,
+        evaluation_options: Option<&EvaluationOptions>,
+    ) -> EvaluationResult<EvaluationDetails<Self::Value>> {
+        client
+            .get_struct_details(flag_key, evaluation_context, evaluation_options)
+            .await
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::{
+        atomic::{AtomicUsize, Ordering},
+        Arc, Mutex,
+    };
+
+    use crate::{
+        provider::{MockFeatureProvider, ProviderMetadata, ResolutionDetails},
+        EvaluationError, EvaluationErrorCode, FlagMetadataValue, FnHook, OpenFeature,
+    };
+
+    use super::*;
+
+    const ENABLED: Flag<bool> = Flag::new("enabled", false);
+    const TITLE: Flag<&str> = Flag::new("title", "Checkout");
+
+    async fn create_client() -> Client {
+        let mut provider = MockFeatureProvider::new();
+        provider.expect_initialize().returning(|_| {});
+        provider.expect_hooks().return_const(vec![]);
+        provider
+            .expect_metadata()
+            .return_const(ProviderMetadata::default());
+        provider.expect_resolve_bool_value().returning(|_, _| {
+            Ok(ResolutionDetails::builder()
+                .value(true)
+                .flag_metadata(FlagMetadata::default().with_value("owner", "provider"))
+                .build())
+        });
+        provider.expect_resolve_string_value().returning(|_, _| {
+            Err(EvaluationError::builder()
+                .code(EvaluationErrorCode::FlagNotFound)
+                .build())
+        });
+
+        OpenFeature::builder()
+            .provider(provider)
+            .build()
+            .await
+            .create_client()
+    }
+
+    #[tokio::test]
+    async fn get() {
+        let client = create_client().await;
+
+        assert!(client.get(&ENABLED, None).await);
+        assert_eq!(client.get(&TITLE, None).await, "Checkout");
+        assert_eq!(
+            client.details(&TITLE, None).await.unwrap_err().code,
+            EvaluationErrorCode::FlagNotFound
+        );
+    }
+
+    #[tokio::test]
+    async fn hooks_and_metadata() {
+        let evaluations = Arc::new(AtomicUsize::new(0));
+        let flag = ENABLED
+            .with_hook(FnHook::default().with_before_fn({
+                let evaluations = evaluations.clone();
+                move |_, _| {
+                    evaluations.fetch_add(1, Ordering::SeqCst);
+                    Ok(None)
+                }
+            }))
+            .with_metadata(
+                FlagMetadata::default()
+                    .with_value("owner", "checkout")
+                    .with_value("ticket", "CHK-1"),
+            );
+        let client = create_client().await;
+
+        let details = client.details(&flag, None).await.unwrap();
+        assert!(details.value);
+        assert_eq!(
+            details.flag_metadata.values.get("owner"),
+            Some(&FlagMetadataValue::String("provider".to_string()))
+        );
+        assert_eq!(
+            details.flag_metadata.values.get("ticket"),
+            Some(&FlagMetadataValue::String("CHK-1".to_string()))
+        );
+        assert_eq!(evaluations.load(Ordering::SeqCst), 1);
+
+        client.get(&ENABLED, None).await;
+        assert_eq!(evaluations.load(Ordering::SeqCst), 1);
+    }
+
+    #[tokio::test]
+    async fn hooks_see_default_value() {
+        let seen = Arc::new(Mutex::new(Vec::new()));
+        let flag = TITLE.with_hook(FnHook::default().with_finally_fn({
+            let seen = seen.clone();
+            move |context, details, _| {
+                seen.lock()
+                    .unwrap()
+                    .push((context.default_value.clone(), details.value.clone()));
+            }
+        }));
+        let client = create_client().await;
+
+        assert_eq!(client.get(&flag, None).await, "Checkout");
+        assert_eq!(
+            *seen.lock().unwrap(),
+            [(Some(Value::from("Checkout")), Value::from("Checkout"))]
+        );
+    }
+}
@@ -0,0 +1,98 @@
+//! Shortcuts to the singleton of [`OpenFeature`].
+//!
+//! They release the lock of the singleton before awaiting anything, e.g. the initialization of
+//! a provider, so they cannot deadlock with each other nor with a provider calling them.
+
+use crate::{provider::FeatureProvider, Client, EvaluationContext, HookWrapper, OpenFeature};
+
+/// Return an instance sharing the state of the singleton, without holding its lock.
+async fn singleton() -> OpenFeature {
+    OpenFeature::singleton().await.shared()
+}
+
+/// Set the default provider of the singleton, see [`OpenFeature::set_provider`].
+pub async fn set_provider<T: FeatureProvider>(provider: T) {
+    singleton().await.set_provider(provider).await;
+}
+
+/// Bind the given `provider` to the corresponding `domain` of the singleton, see
+/// [`OpenFeature::set_named_provider`].
+pub async fn set_named_provider<T: FeatureProvider>(domain: &str, provider: T) {
+    singleton().await.set_named_provider(domain, provider).await;
+}
+
+/// Create a client of the singleton using the provider bound to `domain`, or the default
+/// provider if none is. Pass an empty `domain` for the default client.
+pub async fn client(domain: &str) -> Client {
+    singleton().await.create_named_client(domain)
+}
+
+/// Add a new hook to the global list of hooks of the singleton, see [`OpenFeature::add_hook`].
+pub async fn add_hook<T: Into<HookWrapper>>(hook: T) {
+    singleton().await.add_hook(hook).await;
+}
+
+/// Set the global evaluation context of the singleton, see
+/// [`OpenFeature::set_evaluation_context`].
+pub async fn set_evaluation_context(evaluation_context: EvaluationContext) {
+    singleton()
+        .await
+        .set_evaluation_context(evaluation_context)
+        .await;
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::{
+        atomic::{AtomicUsize, Ordering},
+        Arc,
+    };
+
+    use crate::{
+        provider::{MockFeatureProvider, ProviderMetadata, ResolutionDetails},
+        FnHook,
+    };
+
+    use super::*;
+
+    #[tokio::test]
+    async fn global_functions() {
+        let _lock = crate::api::api::SINGLETON_TEST_LOCK.lock().await;
+
+        let mut provider = MockFeatureProvider::new();
+        provider.expect_initialize().returning(|_| {});
+        provider.expect_on_context_changed().return_const(());
+        provider.expect_hooks().return_const(vec![]);
+        provider
+            .expect_metadata()
+            .return_const(ProviderMetadata::new("Global"));
+        provider
+            .expect_resolve_string_value()
+            .returning(|_, context| {
+                Ok(ResolutionDetails::new(
+                    context.targeting_key.clone().unwrap_or_default(),
+                ))
+            });
+
+        let evaluations = Arc::new(AtomicUsize::new(0));
+        let hook = {
+            let evaluations = evaluations.clone();
+            FnHook::finally_fn(move |context, _, _| {
+                if context.client_metadata.name == "global_functions" {
+                    evaluations.fetch_add(1, Ordering::SeqCst);
+                }
+            })
+        };
+
+        set_named_provider("global_functions", provider).await;
+        add_hook(hook).await;
+        set_evaluation_context(EvaluationContext::default().with_targeting_key("user-1")).await;
+
+        let client = client("global_functions").await;
+        assert_eq!(
+            client.get_string_value("flag", None, None).await,
+            Ok("user-1".to_string())
+        );
+        assert_eq!(evaluations.load(Ordering::SeqCst), 1);
+    }
+}
@@ -1,22 +1,49 @@
 use std::sync::Arc;
 
-use tokio::sync::{RwLock, RwLockReadGuard, RwLockWriteGuard};
+use arc_swap::ArcSwap;
 
 use crate::EvaluationContext;
 
-#[derive(Clone, Default)]
-pub struct GlobalEvaluationContext(Arc<RwLock<EvaluationContext>>);
+use super::notifier::{Notifier, Subscription};
+
+#[derive(Clone)]
+pub struct GlobalEvaluationContext {
+    context: Arc<ArcSwap<EvaluationContext>>,
+    changed: Notifier,
+}
+
+impl Default for GlobalEvaluationContext {
+    fn default() -> Self {
+        Self::new(EvaluationContext::default())
+    }
+}
 
 impl GlobalEvaluationContext {
     pub fn new(evaluation_context: EvaluationContext) -> Self {
-        Self(Arc::new(RwLock::new(evaluation_context)))
+        Self {
+            context: Arc::new(ArcSwap::from_pointee(evaluation_context)),
+            changed: Notifier::default(),
+        }
+    }
+
+    /// Return a snapshot of the context, unaffected by later replacements.
+    pub fn get(&self) -> Arc<EvaluationContext> {
+        self.context.load_full()
+    }
+
+    /// Replace the context and return the previous one.
+    /// The subscribers are not notified until [`GlobalEvaluationContext::notify`] is called.
+    pub fn replace(&self, evaluation_context: EvaluationContext) -> Arc<EvaluationContext> {
+        self.context.swap(Arc::new(evaluation_context))
     }
 
-    pub async fn get(&self) -> RwLockReadGuard<'_, EvaluationContext> {
-        self.0.read().await
+    /// Notify the subscribers that the context was replaced.
+    pub fn notify(&self) {
+        self.changed.notify();
     }
 
-    pub async fn get_mut(&self) -> RwLockWriteGuard<'_, EvaluationContext> {
-        self.0.write().await
+    /// Return a receiver notified every time the context is replaced.
+    pub fn subscribe(&self) -> Subscription {
+        self.changed.subscribe()
     }
 }
@@ -1,18 +1,23 @@
 use std::sync::Arc;
 
-use tokio::sync::{RwLock, RwLockReadGuard, RwLockWriteGuard};
+use arc_swap::ArcSwap;
 
 use crate::HookWrapper;
 
 #[derive(Clone, Default)]
-pub struct GlobalHooks(Arc<RwLock<Vec<HookWrapper>>>);
+pub struct GlobalHooks(Arc<ArcSwap<Vec<HookWrapper>>>);
 
 impl GlobalHooks {
-    pub async fn get(&self) -> RwLockReadGuard<'_, Vec<HookWrapper>> {
-        self.0.read().await
+    /// Return a snapshot of the hooks, unaffected by the hooks added afterwards.
+    pub fn get(&self) -> Arc<Vec<HookWrapper>> {
+        self.0.load_full()
     }
 
-    pub async fn get_mut(&self) -> RwLockWriteGuard<'_, Vec<HookWrapper>> {
-        self.0.write().await
+    pub fn push(&self, hook: &HookWrapper) {
+        self.0.rcu(|hooks| {
+            let mut hooks = Vec::clone(hooks);
+            hooks.push(hook.clone());
+            hooks
+        });
     }
 }
@@ -0,0 +1,130 @@
+use std::{
+    any::Any,
+    future::{poll_fn, Future},
+    panic::{catch_unwind, AssertUnwindSafe},
+    pin::{pin, Pin},
+    task::Poll,
+    time::Duration,
+};
+
+use futures_timer::Delay;
+
+use crate::{EvaluationError, EvaluationErrorCode};
+
+// ============================================================
+//  Interrupted
+// ============================================================
+
+/// The reason a guarded future did not run to completion.
+#[derive(Debug)]
+pub enum Interrupted {
+    Panicked(String),
+    TimedOut(Duration),
+}
+
+impl Interrupted {
+    /// Convert to an [`EvaluationError`] attributed to `source` (e.g. "Provider").
+    pub fn into_error(self, source: &str) -> EvaluationError {
+        match self {
+            Self::Panicked(message) => EvaluationError::builder()
+                .code(EvaluationErrorCode::General(format!("{source} panicked")))
+                .message(message)
+                .build(),
+            Self::TimedOut(timeout) => EvaluationError::builder()
+                .code(EvaluationErrorCode::General(format!("{source} timed out")))
+                .message(format!("Execution exceeded {timeout:?}"))
+                .build(),
+        }
+    }
+}
+
+/// Run `future` to completion, converting a panic into [`Interrupted::Panicked`] and, if
+/// `timeout` is set, giving up with [`Interrupted::TimedOut`] once it elapses.
+pub async fn isolate<F: Future>(
+    timeout: Option<Duration>,
+    future: F,
+) -> Result<F::Output, Interrupted> {
+    match timeout {
+        Some(timeout) => {
+            let mut future = pin!(catch_panic(future));
+            let mut delay = Delay::new(timeout);
+
+            // INFO: Runtime-agnostic timer, the future is polled first not to time out a ready one
+            poll_fn(|cx| match future.as_mut().poll(cx) {
+                Poll::Ready(output) => Poll::Ready(output),
+                Poll::Pending => Pin::new(&mut delay)
+                    .poll(cx)
+                    .map(|()| Err(Interrupted::TimedOut(timeout))),
+            })
+            .await
+        }
+        None => catch_panic(future).await,
+    }
+}
+
+async fn catch_panic<F: Future>(future: F) -> Result<F::Output, Interrupted> {
+    let mut future = pin!(future);
+
+    poll_fn(
+        |cx| match catch_unwind(AssertUnwindSafe(|| future.as_mut().poll(cx))) {
+            Ok(Poll::Ready(output)) => Poll::Ready(Ok(output)),
+            Ok(Poll::Pending) => Poll::Pending,
+            Err(payload) => Poll::Ready(Err(Interrupted::Panicked(panic_message(&*payload)))),
+        },
+    )
+    .await
+}
+
+/// Run `resolve` to completion, converting a panic into [`Interrupted::Panicked`].
+pub fn isolate_sync<T>(resolve: impl FnOnce() -> T) -> Result<T, Interrupted> {
+    catch_unwind(AssertUnwindSafe(resolve))
+        .map_err(|payload| Interrupted::Panicked(panic_message(&*payload)))
+}
+
+fn panic_message(payload: &(dyn Any + Send)) -> String {
+    if let Some(message) = payload.downcast_ref::<&str>() {
+        (*message).to_string()
+    } else if let Some(message) = payload.downcast_ref::<String>() {
+        message.clone()
+    } else {
+        "Unknown panic".to_string()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[tokio::test]
+    async fn isolate_panic() {
+        let result: Result<(), _> = isolate(None, async { panic!("boom") }).await;
+
+        let error = result.unwrap_err().into_error("Provider");
+        assert_eq!(
+            error.code,
+            EvaluationErrorCode::General("Provider panicked".to_string())
+        );
+        assert_eq!(error.message, Some("boom".to_string()));
+    }
+
+    #[tokio::test]
+    async fn isolate_timeout() {
+        let result = isolate(
+            Some(Duration::from_millis(10)),
+            tokio::time::sleep(Duration::from_secs(10)),
+        )
+        .await;
+
+        assert!(matches!(result, Err(Interrupted::TimedOut(_))));
+    }
+
+    #[tokio::test]
+    async fn isolate_completes() {
+        assert_eq!(
+            isolate(Some(Duration::from_secs(10)), async { 42 })
+                .await
+                .unwrap(),
+            42
+        );
+    }
+}
@@ -1,10 +1,31 @@
 mod api;
 pub use api::OpenFeature;
 
+mod global;
+pub use global::{add_hook, client, set_evaluation_context, set_named_provider, set_provider};
+
+mod builder;
+pub use builder::OpenFeatureBuilder;
+
 mod client;
 pub use client::{Client, ClientMetadata};
 
+mod flag;
+pub use flag::{Flag, FlagType};
+
+#[cfg(feature = "blocking")]
+mod blocking_client;
+#[cfg(feature = "blocking")]
+pub use blocking_client::BlockingClient;
+
+mod evaluation_events;
+pub use evaluation_events::{
+    EvaluationEvent, EvaluationEventOptions, EvaluationEventStats, EvaluationEventStream,
+};
+
 mod provider_registry;
 
 mod global_evaluation_context;
 mod global_hooks;
+mod isolation;
+mod notifier;
@@ -0,0 +1,72 @@
+use async_broadcast::{InactiveReceiver, Receiver, RecvError, Sender, TryRecvError};
+
+/// A runtime-agnostic change notification, coalescing the changes a subscriber has not seen yet.
+#[derive(Clone)]
+pub struct Notifier {
+    sender: Sender<()>,
+    // INFO: Kept to leave the channel open while nobody is subscribed
+    _inactive: InactiveReceiver<()>,
+}
+
+impl Default for Notifier {
+    fn default() -> Self {
+        let (mut sender, receiver) = async_broadcast::broadcast(1);
+        sender.set_overflow(true);
+        sender.set_await_active(false);
+
+        Self {
+            sender,
+            _inactive: receiver.deactivate(),
+        }
+    }
+}
+
+impl Notifier {
+    /// Notify the current subscribers.
+    pub fn notify(&self) {
+        let _ = self.sender.try_broadcast(());
+    }
+
+    /// Return a subscription notified of the changes happening from now on.
+    pub fn subscribe(&self) -> Subscription {
+        Subscription(self.sender.new_receiver())
+    }
+}
+
+/// The receiving side of a [`Notifier`].
+pub struct Subscription(Receiver<()>);
+
+impl Subscription {
+    /// Complete once a change happened since the last call.
+    pub async fn changed(&mut self) {
+        if let Err(RecvError::Closed) = self.0.recv_direct().await {
+            // INFO: No more changes once the notifier is dropped
+            std::future::pending::<()>().await;
+        }
+
+        // INFO: Coalesce the changes already queued
+        while !matches!(
+            self.0.try_recv(),
+            Err(TryRecvError::Empty | TryRecvError::Closed)
+        ) {}
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[tokio::test]
+    async fn coalesce_changes() {
+        let notifier = Notifier::default();
+        notifier.notify();
+
+        let mut subscription = notifier.subscribe();
+        notifier.notify();
+        notifier.notify();
+        notifier.notify();
+
+        subscription.changed().await;
+        assert!(subscription.0.is_empty());
+    }
+}
@@ -1,80 +1,163 @@
-use std::sync::Arc;
-use std::{borrow::Borrow, collections::HashMap};
+use std::any::Any;
+use std::collections::HashMap;
+use std::sync::{
+    atomic::{AtomicU64, AtomicUsize, Ordering},
+    Arc,
+};
 
-use tokio::sync::RwLock;
+use arc_swap::ArcSwap;
 
-use crate::provider::{FeatureProvider, NoOpProvider};
+use crate::{
+    provider::{FeatureProvider, NoOpProvider, ProviderStatus, SyncFeatureProvider, SyncProvider},
+    EvaluationContext,
+};
 
-use super::global_evaluation_context::GlobalEvaluationContext;
+use super::{
+    global_evaluation_context::GlobalEvaluationContext,
+    notifier::{Notifier, Subscription},
+};
+
+type Providers = HashMap<String, FeatureProviderWrapper>;
 
 // ============================================================
 //  ProviderRegistry
 // ============================================================
 
+/// The providers bound to each domain.
+///
+/// Evaluations read an immutable snapshot of the map without locking. A registration
+/// initializes its provider first, then atomically swaps in a new snapshot; when registrations
+/// of the same domain overlap, the last one called wins. Replaced and superseded providers are
+/// shut down.
 #[derive(Clone)]
 pub struct ProviderRegistry {
     global_evaluation_context: GlobalEvaluationContext,
-    providers: Arc<RwLock<HashMap<String, FeatureProviderWrapper>>>,
+    providers: Arc<ArcSwap<Providers>>,
+    registrations: Arc<AtomicU64>,
+    cleared_at: Arc<AtomicU64>,
+    changed: Notifier,
 }
 
 impl ProviderRegistry {
     pub fn new(evaluation_context: GlobalEvaluationContext) -> Self {
-        let mut providers: HashMap<String, FeatureProviderWrapper> = HashMap::new();
-        providers.insert(
-            String::default(),
-            FeatureProviderWrapper::new(NoOpProvider::default()),
-        );
-
         Self {
             global_evaluation_context: evaluation_context,
-            providers: Arc::new(RwLock::new(providers)),
+            providers: Arc::new(ArcSwap::from_pointee(Self::no_op_providers())),
+            registrations: Arc::default(),
+            cleared_at: Arc::default(),
+            changed: Notifier::default(),
         }
     }
 
-    pub async fn set_default<T: FeatureProvider>(&self, mut provider: T) {
-        let mut map = self.providers.write().await;
-        map.remove("");
-
-        provider
-            .initialize(self.global_evaluation_context.get().await.borrow())
-            .await;
+    pub async fn set_default<T: FeatureProvider>(&self, provider: T) {
+        self.set_named("", provider).await;
+    }
 
-        map.insert(String::default(), FeatureProviderWrapper::new(provider));
+    pub async fn set_named<T: FeatureProvider>(&self, name: &str, provider: T) {
+        self.bind(name, PendingProvider::new(provider)).await;
     }
 
-    pub async fn set_named<T: FeatureProvider>(&self, name: &str, mut provider: T) {
-        // Drop the already registered provider if any.
-        if self.get_named(name).await.is_some() {
-            self.providers.write().await.remove(name);
+    pub async fn bind(&self, name: &str, provider: PendingProvider) {
+        let registration = self.registrations.fetch_add(1, Ordering::AcqRel) + 1;
+
+        // INFO: Evaluations keep using the previous provider meanwhile
+        let context = self.global_evaluation_context.get();
+        let provider = provider.initialize(&context, registration).await;
+        let mut bound = false;
+        let mut displaced = None;
+
+        self.providers.rcu(|providers| {
+            // INFO: Superseded by a later registration, or by a shutdown
+            bound = registration > self.cleared_at.load(Ordering::Acquire)
+                && providers
+                    .get(name)
+                    .map_or(true, |current| current.registration < registration);
+
+            let mut providers = Providers::clone(providers);
+            displaced = if bound {
+                providers.insert(name.to_string(), provider.clone())
+            } else {
+                None
+            };
+            providers
+        });
+
+        if !bound {
+            provider.provider.shutdown().await;
+            return;
         }
 
-        provider
-            .initialize(self.global_evaluation_context.get().await.borrow())
-            .await;
+        // INFO: A context set while initializing did not reconcile this unbound provider
+        let current_context = self.global_evaluation_context.get();
+        if !Arc::ptr_eq(&context, &current_context) {
+            provider.reconcile(&context, &current_context).await;
+        }
 
-        self.providers
-            .write()
-            .await
-            .insert(name.to_string(), FeatureProviderWrapper::new(provider));
-    }
+        self.changed.notify();
 
-    pub async fn get(&self, name: &str) -> FeatureProviderWrapper {
-        match self.get_named(name).await {
-            Some(provider) => provider,
-            None => self.get_default().await,
+        if let Some(displaced) = displaced {
+            displaced.provider.shutdown().await;
         }
     }
 
-    pub async fn get_default(&self) -> FeatureProviderWrapper {
-        self.providers.read().await.get("").unwrap().clone()
+    pub fn get(&self, name: &str) -> FeatureProviderWrapper {
+        let providers = self.providers.load();
+
+        providers
+            .get(name)
+            .or_else(|| providers.get(""))
+            .cloned()
+            .expect("a default provider is always bound")
+    }
+
+    pub fn get_default(&self) -> FeatureProviderWrapper {
+        self.get("")
+    }
+
+    pub fn get_named(&self, name: &str) -> Option<FeatureProviderWrapper> {
+        self.providers.load().get(name).cloned()
     }
 
-    pub async fn get_named(&self, name: &str) -> Option<FeatureProviderWrapper> {
-        self.providers.read().await.get(name).cloned()
+    /// Call [`FeatureProvider::on_context_changed`] on every registered provider, one after the
+    /// other.
+    pub async fn reconcile(
+        &self,
+        old_context: &EvaluationContext,
+        new_context: &EvaluationContext,
+    ) {
+        let providers = self.providers.load_full();
+
+        for provider in providers.values() {
+            provider.reconcile(old_context, new_context).await;
+        }
     }
 
+    /// Unbind and shut down all the providers, leaving the no-op provider as default.
+    /// Registrations still initializing are discarded.
     pub async fn clear(&self) {
-        self.providers.write().await.clear();
+        let registration = self.registrations.fetch_add(1, Ordering::AcqRel) + 1;
+        self.cleared_at.fetch_max(registration, Ordering::AcqRel);
+
+        let providers = self.providers.swap(Arc::new(Self::no_op_providers()));
+        self.changed.notify();
+
+        for provider in providers.values() {
+            provider.provider.shutdown().await;
+        }
+    }
+
+    fn no_op_providers() -> Providers {
+        let mut providers = Providers::new();
+        providers.insert(
+            String::default(),
+            FeatureProviderWrapper::new(NoOpProvider::default()),
+        );
+        providers
+    }
+
+    /// Return a receiver notified every time a provider is bound or unbound.
+    pub fn subscribe(&self) -> Subscription {
+        self.changed.subscribe()
     }
 }
 
@@ -84,19 +167,459 @@ impl Default for ProviderRegistry {
     }
 }
 
+// ============================================================
+//  PendingProvider
+// ============================================================
+
+/// A provider waiting to be initialized and bound.
+pub enum PendingProvider {
+    Async(Box<dyn FeatureProvider>),
+    // INFO: A SyncProvider is kept apart, so that its flags are resolved without going through
+    // the futures of the FeatureProvider trait
+    Sync(SyncProvider),
+}
+
+impl PendingProvider {
+    pub fn new<T: FeatureProvider>(provider: T) -> Self {
+        let provider: Box<dyn Any> = Box::new(provider);
+
+        match provider.downcast::<SyncProvider>() {
+            Ok(provider) => Self::Sync(*provider),
+            Err(provider) => Self::Async(
+                provider
+                    .downcast::<T>()
+                    .expect("the provider is of its own type"),
+            ),
+        }
+    }
+
+    async fn initialize(
+        self,
+        context: &EvaluationContext,
+        registration: u64,
+    ) -> FeatureProviderWrapper {
+        let (provider, sync): (Arc<dyn FeatureProvider>, _) = match self {
+            Self::Async(mut provider) => {
+                provider.initialize(context).await;
+                (Arc::from(provider), None)
+            }
+            Self::Sync(mut provider) => {
+                provider.initialize(context).await;
+                let sync = provider.get();
+                (Arc::new(provider), Some(sync))
+            }
+        };
+
+        FeatureProviderWrapper {
+            provider,
+            sync,
+            reconciling: Arc::default(),
+            registration,
+        }
+    }
+}
+
 // ============================================================
 //  FeatureProviderWrapper
 // ============================================================
 
 #[derive(Clone)]
-pub struct FeatureProviderWrapper(Arc<dyn FeatureProvider>);
+pub struct FeatureProviderWrapper {
+    provider: Arc<dyn FeatureProvider>,
+    sync: Option<Arc<dyn SyncFeatureProvider>>,
+    reconciling: Arc<AtomicUsize>,
+    registration: u64,
+}
 
 impl FeatureProviderWrapper {
     pub fn new(provider: impl FeatureProvider) -> Self {
-        Self(Arc::new(provider))
+        Self {
+            provider: Arc::new(provider),
+            sync: None,
+            reconciling: Arc::default(),
+            registration: 0,
+        }
     }
 
     pub fn get(&self) -> Arc<dyn FeatureProvider> {
-        self.0.clone()
+        self.provider.clone()
+    }
+
+    /// Return the provider adapted by a [`SyncProvider`], if any.
+    pub fn get_sync(&self) -> Option<Arc<dyn SyncFeatureProvider>> {
+        self.sync.clone()
+    }
+
+    /// Return the status of the provider, RECONCILING while it handles a context change.
+    pub fn status(&self) -> ProviderStatus {
+        if self.reconciling.load(Ordering::Acquire) > 0 {
+            ProviderStatus::Reconciling
+        } else {
+            self.provider.status()
+        }
+    }
+
+    async fn reconcile(&self, old_context: &EvaluationContext, new_context: &EvaluationContext) {
+        // INFO: Counted, as context changes may overlap, and released even if the provider panics
+        let _reconciling = Reconciling::start(&self.reconciling);
+
+        self.provider
+            .on_context_changed(old_context, new_context)
+            .await;
+    }
+}
+
+/// The guard of a context change handled by a provider.
+struct Reconciling<'a>(&'a AtomicUsize);
+
+impl<'a> Reconciling<'a> {
+    fn start(reconciling: &'a AtomicUsize) -> Self {
+        reconciling.fetch_add(1, Ordering::AcqRel);
+        Self(reconciling)
+    }
+}
+
+impl Drop for Reconciling<'_> {
+    fn drop(&mut self) {
+        self.0.fetch_sub(1, Ordering::AcqRel);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::Arc;
+
+    use async_trait::async_trait;
+    use tokio::sync::{Notify, Semaphore};
+
+    use crate::{
+        provider::{MockFeatureProvider, ProviderMetadata, ResolutionDetails, ValueProvider},
+        EvaluationResult, StructValue, Type, Value,
+    };
+
+    use super::*;
+
+    /// A provider whose initialization lasts until it is released.
+    struct SlowProvider {
+        metadata: ProviderMetadata,
+        started: Arc<Notify>,
+        release: Arc<Notify>,
+        contexts: Arc<std::sync::Mutex<Vec<EvaluationContext>>>,
+        shutdowns: Arc<AtomicUsize>,
+    }
+
+    impl SlowProvider {
+        fn new() -> Self {
+            Self {
+                metadata: ProviderMetadata::new("Slow Provider"),
+                started: Arc::default(),
+                release: Arc::default(),
+                contexts: Arc::default(),
+                shutdowns: Arc::default(),
+            }
+        }
+    }
+
+    #[async_trait]
+    impl ValueProvider for SlowProvider {
+        async fn initialize(&mut self, _context: &EvaluationContext) {
+            self.started.notify_one();
+            self.release.notified().await;
+        }
+
+        async fn on_context_changed(
+            &self,
+            _old_context: &EvaluationContext,
+            new_context: &EvaluationContext,
+        ) {
+            self.contexts.lock().unwrap().push(new_context.clone());
+        }
+
+        fn metadata(&self) -> &ProviderMetadata {
+            &self.metadata
+        }
+
+        async fn resolve_value(
+            &self,
+            _flag_key: &str,
+            _expected: Type,
+            _evaluation_context: &EvaluationContext,
+        ) -> EvaluationResult<ResolutionDetails<Value>> {
+            Ok(ResolutionDetails::new(true))
+        }
+
+        async fn shutdown(&self) {
+            self.shutdowns.fetch_add(1, Ordering::SeqCst);
+        }
+    }
+
+    /// A provider whose context changes last until a permit is released, and panic once the
+    /// permits are closed.
+    struct ReconcilingProvider {
+        metadata: ProviderMetadata,
+        permits: Arc<Semaphore>,
+    }
+
+    #[async_trait]
+    impl ValueProvider for ReconcilingProvider {
+        async fn on_context_changed(
+            &self,
+            _old_context: &EvaluationContext,
+            _new_context: &EvaluationContext,
+        ) {
+            self.permits
+                .acquire()
+                .await
+                .expect("permits are open")
+                .forget();
+        }
+
+        fn metadata(&self) -> &ProviderMetadata {
+            &self.metadata
+        }
+
+        async fn resolve_value(
+            &self,
+            _flag_key: &str,
+            _expected: Type,
+            _evaluation_context: &EvaluationContext,
+        ) -> EvaluationResult<ResolutionDetails<Value>> {
+            Ok(ResolutionDetails::new(true))
+        }
+    }
+
+    fn fast_provider() -> MockFeatureProvider {
+        let mut provider = MockFeatureProvider::new();
+        provider.expect_initialize().returning(|_| {});
+        provider
+            .expect_metadata()
+            .return_const(ProviderMetadata::new("Fast Provider"));
+        provider
+    }
+
+    fn name_of(provider: &FeatureProviderWrapper) -> String {
+        provider.get().metadata().name.clone()
+    }
+
+    /// Start binding `provider` to `name`, returning once it is initializing.
+    async fn start_slow_registration(
+        registry: &ProviderRegistry,
+        name: &'static str,
+        provider: SlowProvider,
+    ) -> (Arc<Notify>, tokio::task::JoinHandle<()>) {
+        let started = provider.started.clone();
+        let release = provider.release.clone();
+
+        let registry = registry.clone();
+        let registration = tokio::spawn(async move { registry.set_named(name, provider).await });
+        started.notified().await;
+
+        (release, registration)
+    }
+
+    #[tokio::test]
+    async fn initialize_before_binding() {
+        let registry = ProviderRegistry::default();
+        let (release, registration) =
+            start_slow_registration(&registry, "", SlowProvider::new()).await;
+
+        assert_eq!(name_of(&registry.get("")), "No-op Provider");
+
+        release.notify_one();
+        registration.await.unwrap();
+
+        assert_eq!(name_of(&registry.get("")), "Slow Provider");
+    }
+
+    /// A synchronous provider resolving every flag to its default value.
+    struct DefaultProvider(ProviderMetadata);
+
+    impl SyncFeatureProvider for DefaultProvider {
+        fn metadata(&self) -> &ProviderMetadata {
+            &self.0
+        }
+
+        fn resolve_bool_value(
+            &self,
+            _flag_key: &str,
+            _evaluation_context: &EvaluationContext,
+        ) -> EvaluationResult<ResolutionDetails<bool>> {
+            Ok(ResolutionDetails::default())
+        }
+
+        fn resolve_int_value(
+            &self,
+            _flag_key: &str,
+            _evaluation_context: &EvaluationContext,
+        ) -> EvaluationResult<ResolutionDetails<i64>> {
+            Ok(ResolutionDetails::default())
+        }
+
+        fn resolve_float_value(
+            &self,
+            _flag_key: &str,
+            _evaluation_context: &EvaluationContext,
+        ) -> EvaluationResult<ResolutionDetails<f64>> {
+            Ok(ResolutionDetails::default())
+        }
+
+        fn resolve_string_value(
+            &self,
+            _flag_key: &str,
+            _evaluation_context: &EvaluationContext,
+        ) -> EvaluationResult<ResolutionDetails<String>> {
+            Ok(ResolutionDetails::default())
+        }
+
+        fn resolve_struct_value(
+            &self,
+            _flag_key: &str,
+            _evaluation_context: &EvaluationContext,
+        ) -> EvaluationResult<ResolutionDetails<StructValue>> {
+            Ok(ResolutionDetails::default())
+        }
+    }
+
+    #[tokio::test]
+    async fn keep_sync_provider_apart() {
+        let registry = ProviderRegistry::default();
+        registry
+            .set_default(SyncProvider::new(DefaultProvider(ProviderMetadata::new(
+                "Sync Provider",
+            ))))
+            .await;
+        registry.set_named("domain", fast_provider()).await;
+
+        let sync = registry.get("").get_sync().unwrap();
+        assert_eq!(sync.metadata().name, "Sync Provider");
+        assert_eq!(name_of(&registry.get("")), "Sync Provider");
+        assert!(registry.get("domain").get_sync().is_none());
+    }
+
+    #[tokio::test]
+    async fn last_registration_wins() {
+        let registry = ProviderRegistry::default();
+        let (release, registration) =
+            start_slow_registration(&registry, "domain", SlowProvider::new()).await;
+
+        registry.set_named("domain", fast_provider()).await;
+        release.notify_one();
+        registration.await.unwrap();
+
+        assert_eq!(name_of(&registry.get("domain")), "Fast Provider");
+    }
+
+    #[tokio::test]
+    async fn clear_leaves_no_op_provider() {
+        let registry = ProviderRegistry::default();
+        registry.set_default(fast_provider()).await;
+        registry.set_named("domain", fast_provider()).await;
+        let (release, registration) =
+            start_slow_registration(&registry, "pending", SlowProvider::new()).await;
+
+        registry.clear().await;
+        release.notify_one();
+        registration.await.unwrap();
+
+        assert_eq!(name_of(&registry.get("")), "No-op Provider");
+        assert_eq!(name_of(&registry.get("domain")), "No-op Provider");
+        assert!(registry.get_named("pending").is_none());
+    }
+
+    #[tokio::test]
+    async fn reconcile_context_set_while_initializing() {
+        let evaluation_context = GlobalEvaluationContext::default();
+        let registry = ProviderRegistry::new(evaluation_context.clone());
+
+        let provider = SlowProvider::new();
+        let started = provider.started.clone();
+        let release = provider.release.clone();
+        let contexts = provider.contexts.clone();
+
+        let registration = tokio::spawn({
+            let registry = registry.clone();
+            async move { registry.set_default(provider).await }
+        });
+        started.notified().await;
+
+        let new_context = EvaluationContext::default().with_targeting_key("user-1");
+        let old_context = evaluation_context.replace(new_context.clone());
+        registry.reconcile(&old_context, &new_context).await;
+
+        release.notify_one();
+        registration.await.unwrap();
+
+        assert_eq!(*contexts.lock().unwrap(), [new_context]);
+    }
+
+    #[tokio::test]
+    async fn shut_down_displaced_providers() {
+        let registry = ProviderRegistry::default();
+
+        let replaced = SlowProvider::new();
+        let replaced_shutdowns = replaced.shutdowns.clone();
+        let (release, registration) = start_slow_registration(&registry, "domain", replaced).await;
+        release.notify_one();
+        registration.await.unwrap();
+
+        let superseded = SlowProvider::new();
+        let superseded_shutdowns = superseded.shutdowns.clone();
+        let (release, registration) =
+            start_slow_registration(&registry, "domain", superseded).await;
+
+        let current = SlowProvider::new();
+        let current_shutdowns = current.shutdowns.clone();
+        let (current_release, current_registration) =
+            start_slow_registration(&registry, "domain", current).await;
+        current_release.notify_one();
+        current_registration.await.unwrap();
+        assert_eq!(replaced_shutdowns.load(Ordering::SeqCst), 1);
+
+        release.notify_one();
+        registration.await.unwrap();
+        assert_eq!(superseded_shutdowns.load(Ordering::SeqCst), 1);
+        assert_eq!(current_shutdowns.load(Ordering::SeqCst), 0);
+
+        registry.clear().await;
+        assert_eq!(current_shutdowns.load(Ordering::SeqCst), 1);
+        assert_eq!(replaced_shutdowns.load(Ordering::SeqCst), 1);
+    }
+
+    #[tokio::test]
+    async fn reconcile_overlapping_context_changes() {
+        let permits = Arc::new(Semaphore::new(0));
+        let provider = FeatureProviderWrapper::new(ReconcilingProvider {
+            metadata: ProviderMetadata::new("Reconciling Provider"),
+            permits: permits.clone(),
+        });
+        let reconcile = |provider: FeatureProviderWrapper| {
+            tokio::spawn(async move {
+                let context = EvaluationContext::default();
+                provider.reconcile(&context, &context).await;
+            })
+        };
+        let reconciling = |count: usize| {
+            let provider = provider.clone();
+            async move {
+                while provider.reconciling.load(Ordering::Acquire) != count {
+                    tokio::task::yield_now().await;
+                }
+            }
+        };
+
+        let first = reconcile(provider.clone());
+        let second = reconcile(provider.clone());
+        reconciling(2).await;
+
+        // INFO: Still reconciling once the first context change is handled
+        permits.add_permits(1);
+        reconciling(1).await;
+        assert_eq!(provider.status(), ProviderStatus::Reconciling);
+
+        // INFO: Done reconciling even though the second one panicked
+        permits.close();
+        let (first, second) = (first.await, second.await);
+        assert!(first.is_ok() != second.is_ok());
+        assert_eq!(provider.status(), ProviderStatus::Ready);
     }
 }
@@ -1,6 +1,9 @@
-use std::collections::HashMap;
+use std::{
+    collections::{HashMap, HashSet},
+    fmt,
+};
 
-use crate::EvaluationContextFieldValue;
+use crate::{EvaluationContextFieldValue, Redaction};
 
 /// The evaluation context provides ambient information for the purposes of flag evaluation.
 /// Contextual data may be used as the basis for targeting, including rule-based evaluation,
@@ -11,7 +14,11 @@ use crate::EvaluationContextFieldValue;
 /// define rules that return a specific value based on the user's email address, locale, or the
 /// time of day. The context provides this information. The context can be optionally provided at
 /// evaluation, and mutated in before hooks.
-#[derive(Clone, Default, PartialEq, Debug)]
+///
+/// Custom fields holding sensitive data can be marked as private, and so can the targeting key
+/// through [`EvaluationContext::TARGETING_KEY`]. Providers see them as usual, but they are
+/// redacted wherever the context leaves the SDK, including its [`fmt::Debug`] output.
+#[derive(Clone, Default, PartialEq)]
 pub struct EvaluationContext {
     /// The targeting key uniquely identifies the subject (end-user, or client service) of a flag
     /// evaluation. Providers may require this field for fractional flag evaluation, rules, or
@@ -22,9 +29,17 @@ pub struct EvaluationContext {
     /// The evaluation context MUST support the inclusion of custom fields, having keys of type
     /// string, and values of type boolean | string | number | datetime | structure.
     pub custom_fields: HashMap<String, EvaluationContextFieldValue>,
+
+    /// Keys of the fields that must be redacted outside of flag resolution.
+    private_fields: HashSet<String>,
 }
 
 impl EvaluationContext {
+    /// The key marking the targeting key as private, as in
+    /// `context.mark_private(EvaluationContext::TARGETING_KEY)`. It is reserved by the
+    /// specification, so no custom field should use it.
+    pub const TARGETING_KEY: &'static str = "targetingKey";
+
     /// Set the `targeting_key` of the evaluation context.
     #[must_use]
     pub fn with_targeting_key(mut self, targeting_key: impl Into<String>) -> Self {
@@ -52,6 +67,86 @@ impl EvaluationContext {
         self.custom_fields.insert(key.into(), value.into());
     }
 
+    /// Add `key` and `value` to the custom field of evaluation context, marking it as private.
+    #[must_use]
+    pub fn with_private_field(
+        mut self,
+        key: impl Into<String>,
+        value: impl Into<EvaluationContextFieldValue>,
+    ) -> Self {
+        self.add_private_field(key, value);
+        self
+    }
+
+    /// Add `key` and `value` to the custom field of evaluation context, marking it as private.
+    ///
+    /// The key stays private even if its value is replaced later on.
+    pub fn add_private_field(
+        &mut self,
+        key: impl Into<String>,
+        value: impl Into<EvaluationContextFieldValue>,
+    ) {
+        let key = key.into();
+        self.mark_private(key.clone());
+        self.custom_fields.insert(key, value.into());
+    }
+
+    /// Set the `targeting_key` of the evaluation context, marking it as private.
+    #[must_use]
+    pub fn with_private_targeting_key(mut self, targeting_key: impl Into<String>) -> Self {
+        self.mark_private(Self::TARGETING_KEY);
+        self.with_targeting_key(targeting_key)
+    }
+
+    /// Mark the custom field `key`, or the targeting key given [`EvaluationContext::TARGETING_KEY`],
+    /// as private, whether it is set yet or not.
+    pub fn mark_private(&mut self, key: impl Into<String>) {
+        self.private_fields.insert(key.into());
+    }
+
+    /// Return `true` if the custom field `key`, or the targeting key given
+    /// [`EvaluationContext::TARGETING_KEY`], is private.
+    pub fn is_private(&self, key: &str) -> bool {
+        self.private_fields.contains(key)
+    }
+
+    /// Return a copy of `self` where private fields are replaced according to `redaction`.
+    ///
+    /// The returned context has no private fields left, so it is safe to log or export as is.
+    #[must_use]
+    pub fn redacted(&self, redaction: &Redaction) -> Self {
+        let custom_fields = self
+            .custom_fields
+            .iter()
+            .map(|(key, value)| {
+                let value = if self.is_private(key) {
+                    redaction.apply(value)
+                } else {
+                    value.clone()
+                };
+
+                (key.clone(), value)
+            })
+            .collect();
+
+        Self {
+            targeting_key: self.redacted_targeting_key(redaction),
+            custom_fields,
+            private_fields: HashSet::new(),
+        }
+    }
+
+    /// Return the targeting key, replaced according to `redaction` if it is private.
+    pub(crate) fn redacted_targeting_key(&self, redaction: &Redaction) -> Option<String> {
+        let targeting_key = self.targeting_key.as_deref()?;
+
+        Some(if self.is_private(Self::TARGETING_KEY) {
+            redaction.apply_str(targeting_key)
+        } else {
+            targeting_key.to_string()
+        })
+    }
+
     /// Merge `other` into `self` if corresponding field is not set.
     /// Meaning values set into `self` has higher precedence.
     pub fn merge_missing(&mut self, other: &Self) {
@@ -66,6 +161,22 @@ impl EvaluationContext {
                 self.custom_fields.insert(key.clone(), value.clone());
             }
         });
+
+        // INFO: A field marked as private anywhere stays private, whichever value wins.
+        self.private_fields
+            .extend(other.private_fields.iter().cloned());
+    }
+}
+
+impl fmt::Debug for EvaluationContext {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let redacted = self.redacted(&Redaction::Mask);
+
+        f.debug_struct("EvaluationContext")
+            .field("targeting_key", &redacted.targeting_key)
+            .field("custom_fields", &redacted.custom_fields)
+            .field("private_fields", &self.private_fields)
+            .finish()
     }
 }
 
@@ -138,6 +249,87 @@ mod tests {
         assert_eq!(context, other);
     }
 
+    #[test]
+    fn merge_missing_keeps_private_fields() {
+        let mut context = EvaluationContext::default().with_custom_field("email", "a@b.c");
+
+        context.merge_missing(
+            &EvaluationContext::default()
+                .with_private_field("email", "x@y.z")
+                .with_private_field("ssn", "123"),
+        );
+
+        assert_eq!(context.custom_fields["email"].as_str(), Some("a@b.c"));
+        assert!(context.is_private("email"));
+        assert!(context.is_private("ssn"));
+
+        context.mark_private("plan");
+        assert!(context.is_private("plan"));
+    }
+
+    #[test]
+    fn redacted() {
+        let context = EvaluationContext::default()
+            .with_targeting_key("user-1")
+            .with_custom_field("plan", "pro")
+            .with_private_field("email", "user@example.com");
+
+        let redacted = context.redacted(&Redaction::Mask);
+
+        assert_eq!(redacted.targeting_key.as_deref(), Some("user-1"));
+        assert_eq!(redacted.custom_fields["plan"].as_str(), Some("pro"));
+        assert_eq!(
+            redacted.custom_fields["email"].as_str(),
+            Some(crate::REDACTED)
+        );
+        assert!(!redacted.is_private("email"));
+
+        // The original is untouched for providers.
+        assert_eq!(
+            context.custom_fields["email"].as_str(),
+            Some("user@example.com")
+        );
+    }
+
+    #[test]
+    fn redacted_targeting_key() {
+        let context = EvaluationContext::default().with_private_targeting_key("user-1");
+        assert!(context.is_private(EvaluationContext::TARGETING_KEY));
+
+        assert_eq!(
+            context.redacted(&Redaction::Mask).targeting_key.as_deref(),
+            Some(crate::REDACTED)
+        );
+        assert_eq!(
+            context.redacted(&Redaction::hash("salt")).targeting_key,
+            Redaction::hash("salt")
+                .apply(&"user-1".into())
+                .as_str()
+                .map(ToString::to_string)
+        );
+        assert!(!format!("{context:?}").contains("user-1"));
+
+        // Marking it private holds for a targeting key merged in later on.
+        let mut context = EvaluationContext::default();
+        context.mark_private(EvaluationContext::TARGETING_KEY);
+        context.merge_missing(&EvaluationContext::default().with_targeting_key("user-2"));
+
+        assert_eq!(
+            context.redacted(&Redaction::Mask).targeting_key.as_deref(),
+            Some(crate::REDACTED)
+        );
+    }
+
+    #[test]
+    fn debug_masks_private_fields() {
+        let context = EvaluationContext::default().with_private_field("email", "user@example.com");
+
+        let output = format!("{context:?}");
+
+        assert!(!output.contains("user@example.com"));
+        assert!(output.contains(crate::REDACTED));
+    }
+
     #[derive(Clone, PartialEq, Eq, Debug)]
     pub struct DummyStruct {
         pub id: i64,
@@ -0,0 +1,211 @@
+use time::OffsetDateTime;
+
+use crate::{EvaluationContext, EvaluationContextFieldValue};
+
+// ============================================================
+//  ContextField
+// ============================================================
+
+/// The types of the fields of a struct deriving `IntoEvaluationContext`.
+///
+/// Implemented for the types of [`EvaluationContextFieldValue`] but structs, for `u64` and
+/// `usize`, always added as their decimal string, for [`Option`]s of them, absent when [`None`],
+/// and for every struct deriving `IntoEvaluationContext`, whose fields are flattened under the key
+/// of the struct, e.g. `tenant.plan`.
+pub trait ContextField {
+    /// Add `self` to `context` as the custom field `key`, marking it as private if `private`.
+    fn add_to_context(&self, context: &mut EvaluationContext, key: &str, private: bool);
+}
+
+macro_rules! impl_context_field {
+    ($($type:ty),*) => {
+        $(
+            impl ContextField for $type {
+                fn add_to_context(&self, context: &mut EvaluationContext, key: &str, private: bool) {
+                    add_field(context, key, self.clone(), private);
+                }
+            }
+        )*
+    };
+}
+
+impl_context_field!(
+    bool,
+    i8,
+    i16,
+    i32,
+    i64,
+    u8,
+    u16,
+    u32,
+    f32,
+    f64,
+    String,
+    OffsetDateTime
+);
+
+macro_rules! impl_unsigned_context_field {
+    ($($type:ty),*) => {
+        $(
+            // INFO: Always added as a string, as not every value fits in an int, so that targeting
+            // rules match a field of these types the same way whatever its value
+            impl ContextField for $type {
+                fn add_to_context(&self, context: &mut EvaluationContext, key: &str, private: bool) {
+                    add_field(context, key, self.to_string(), private);
+                }
+            }
+        )*
+    };
+}
+
+impl_unsigned_context_field!(u64, usize);
+
+impl ContextField for str {
+    fn add_to_context(&self, context: &mut EvaluationContext, key: &str, private: bool) {
+        add_field(context, key, self, private);
+    }
+}
+
+impl<T: ContextField + ?Sized> ContextField for &T {
+    fn add_to_context(&self, context: &mut EvaluationContext, key: &str, private: bool) {
+        (**self).add_to_context(context, key, private);
+    }
+}
+
+impl<T: ContextField> ContextField for Option<T> {
+    fn add_to_context(&self, context: &mut EvaluationContext, key: &str, private: bool) {
+        if let Some(value) = self {
+            value.add_to_context(context, key, private);
+        }
+    }
+}
+
+fn add_field(
+    context: &mut EvaluationContext,
+    key: &str,
+    value: impl Into<EvaluationContextFieldValue>,
+    private: bool,
+) {
+    if private {
+        context.add_private_field(key, value);
+    } else {
+        context.add_custom_field(key, value);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use open_feature_derive::IntoEvaluationContext;
+
+    use super::*;
+
+    #[derive(IntoEvaluationContext)]
+    struct User {
+        #[targeting_key]
+        id: String,
+        #[context(private)]
+        email: String,
+        #[context(rename = "signupDate")]
+        signup_date: OffsetDateTime,
+        nickname: Option<String>,
+        #[context(skip)]
+        #[allow(dead_code)]
+        session: Vec<u8>,
+        tenant: Tenant,
+        #[context(private)]
+        billing: Billing,
+    }
+
+    #[derive(IntoEvaluationContext)]
+    struct Tenant {
+        #[targeting_key]
+        id: u32,
+        plan: &'static str,
+    }
+
+    #[derive(IntoEvaluationContext)]
+    struct Billing {
+        country: Option<String>,
+    }
+
+    #[derive(IntoEvaluationContext)]
+    struct Device {
+        #[targeting_key]
+        id: u64,
+        owner: Option<Account>,
+        sessions: usize,
+    }
+
+    #[derive(IntoEvaluationContext)]
+    struct Account {
+        #[targeting_key]
+        id: u64,
+    }
+
+    fn user() -> User {
+        User {
+            id: "user-1".to_string(),
+            email: "bob@example.com".to_string(),
+            signup_date: OffsetDateTime::UNIX_EPOCH,
+            nickname: None,
+            session: vec![42],
+            tenant: Tenant {
+                id: 7,
+                plan: "enterprise",
+            },
+            billing: Billing {
+                country: Some("FR".to_string()),
+            },
+        }
+    }
+
+    #[test]
+    fn derive_evaluation_context() {
+        let user = user();
+
+        assert_eq!(
+            EvaluationContext::from(&user),
+            EvaluationContext::default()
+                .with_targeting_key("user-1")
+                .with_private_field("email", "bob@example.com")
+                .with_custom_field("signupDate", OffsetDateTime::UNIX_EPOCH)
+                .with_custom_field("tenant.id", "7")
+                .with_custom_field("tenant.plan", "enterprise")
+                .with_private_field("billing.country", "FR")
+        );
+
+        let context: EvaluationContext = Tenant {
+            id: 7,
+            plan: "free",
+        }
+        .into();
+        assert_eq!(context.targeting_key.as_deref(), Some("7"));
+        assert_eq!(context.custom_fields.len(), 1);
+    }
+
+    #[test]
+    fn derive_unsigned_targeting_key() {
+        let device = Device {
+            id: u64::MAX,
+            owner: Some(Account { id: 42 }),
+            sessions: 3,
+        };
+
+        assert_eq!(
+            EvaluationContext::from(&device),
+            EvaluationContext::default()
+                .with_targeting_key(u64::MAX.to_string())
+                .with_custom_field("owner.id", "42")
+                .with_custom_field("sessions", "3")
+        );
+
+        let mut context = EvaluationContext::default();
+        u64::MAX.add_to_context(&mut context, "big", false);
+        0_usize.add_to_context(&mut context, "small", false);
+        assert_eq!(
+            context.custom_fields["big"].as_str(),
+            Some("18446744073709551615")
+        );
+        assert_eq!(context.custom_fields["small"].as_str(), Some("0"));
+    }
+}
@@ -1,7 +1,7 @@
 use std::collections::HashMap;
 use std::fmt::{Display, Formatter};
 
-use crate::EvaluationError;
+use crate::{EvaluationError, EvaluationErrorCode};
 
 use super::Value;
 
@@ -31,6 +31,12 @@ pub struct EvaluationDetails<T> {
     /// The optional flag metadata returned by the configured provider.
     /// If the provider returns nothing, it is set to the default value.
     pub flag_metadata: FlagMetadata,
+
+    /// The error code of an abnormal evaluation, as seen by the finally hooks.
+    pub error_code: Option<EvaluationErrorCode>,
+
+    /// The error message of an abnormal evaluation, as seen by the finally hooks.
+    pub error_message: Option<String>,
 }
 
 impl EvaluationDetails<Value> {
@@ -42,6 +48,22 @@ impl EvaluationDetails<Value> {
             reason: Some(EvaluationReason::Error),
             variant: None,
             flag_metadata: FlagMetadata::default(),
+            error_code: None,
+            error_message: None,
+        }
+    }
+
+    /// Creates a new `EvaluationDetails` instance with an error reason and the code and message
+    /// of `error`.
+    pub fn from_error(
+        flag_key: impl Into<String>,
+        value: impl Into<Value>,
+        error: &EvaluationError,
+    ) -> Self {
+        Self {
+            error_code: Some(error.code.clone()),
+            error_message: error.message.clone(),
+            ..Self::error_reason(flag_key, value)
         }
     }
 }
@@ -58,6 +80,8 @@ where
             reason: self.reason,
             variant: self.variant,
             flag_metadata: self.flag_metadata,
+            error_code: self.error_code,
+            error_message: self.error_message,
         }
     }
 }
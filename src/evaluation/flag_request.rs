@@ -0,0 +1,27 @@
+use crate::{Type, Value};
+
+/// A flag to evaluate in a batch with [`Client::evaluate_many`](crate::Client::evaluate_many).
+#[derive(Clone, PartialEq, Debug)]
+pub struct FlagRequest {
+    /// The key of the flag.
+    pub flag_key: String,
+
+    /// The value returned on error. Its type is the expected type of the flag.
+    pub default_value: Value,
+}
+
+impl FlagRequest {
+    /// Create a new request for `flag_key`, expecting a value of the same type as
+    /// `default_value`.
+    pub fn new(flag_key: impl Into<String>, default_value: impl Into<Value>) -> Self {
+        Self {
+            flag_key: flag_key.into(),
+            default_value: default_value.into(),
+        }
+    }
+
+    /// Return the expected type of the flag.
+    pub fn flag_type(&self) -> Type {
+        self.default_value.get_type()
+    }
+}
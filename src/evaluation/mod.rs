@@ -9,11 +9,23 @@ pub use error::{EvaluationError, EvaluationErrorCode};
 mod context;
 pub use context::EvaluationContext;
 
+mod redaction;
+pub use redaction::{Redaction, REDACTED};
+
 mod context_field_value;
 pub use context_field_value::EvaluationContextFieldValue;
 
+mod context_field;
+pub use context_field::ContextField;
+
 mod value;
 pub use value::{StructValue, Type, Value};
 
+mod struct_field;
+pub use struct_field::{StructElement, StructField, StructFieldError};
+
 mod options;
 pub use options::EvaluationOptions;
+
+mod flag_request;
+pub use flag_request::FlagRequest;
@@ -1,5 +1,3 @@
-use crate::Hook;
-
 /// Contain hooks.
 #[derive(Default, Clone)]
 pub struct EvaluationOptions {
@@ -8,18 +6,26 @@ pub struct EvaluationOptions {
 
     /// Hints to be passed to the hooks.
     pub hints: crate::hooks::HookHints,
+
+    /// The declared default value of a typed flag, reported instead of the zero value of the
+    /// evaluated type.
+    pub(crate) default_value: Option<crate::Value>,
 }
 
 impl EvaluationOptions {
     /// Create a new instance of `EvaluationOptions`.
     pub fn new(hooks: Vec<crate::hooks::HookWrapper>, hints: crate::hooks::HookHints) -> Self {
-        Self { hooks, hints }
+        Self {
+            hooks,
+            hints,
+            default_value: None,
+        }
     }
 
     /// Add a hook to the evaluation options.
     #[must_use]
-    pub fn with_hook<T: Hook + 'static>(mut self, hook: T) -> Self {
-        self.hooks.push(crate::hooks::HookWrapper::new(hook));
+    pub fn with_hook<T: Into<crate::hooks::HookWrapper>>(mut self, hook: T) -> Self {
+        self.hooks.push(hook.into());
         self
     }
 }
@@ -0,0 +1,107 @@
+use std::fmt::Write;
+
+use sha2::{Digest, Sha256};
+
+use crate::EvaluationContextFieldValue;
+
+/// The placeholder that replaces a masked private field.
+pub const REDACTED: &str = "[REDACTED]";
+
+/// How private evaluation context fields are rendered by hooks and exporters.
+///
+/// Providers always receive the original values; redaction only applies to what leaves the SDK
+/// through logs, telemetry and serialized exports.
+#[derive(Clone, Default, PartialEq, Eq, Debug)]
+pub enum Redaction {
+    /// Replace the value with [`REDACTED`].
+    #[default]
+    Mask,
+
+    /// Replace the value with the hex encoded SHA-256 digest of `salt` followed by the value, so
+    /// equal values can still be correlated without being revealed.
+    Hash {
+        /// The salt prepended to every value before hashing.
+        salt: String,
+    },
+}
+
+impl Redaction {
+    /// Create a [`Redaction::Hash`] with given `salt`.
+    pub fn hash(salt: impl Into<String>) -> Self {
+        Self::Hash { salt: salt.into() }
+    }
+
+    /// Return the redacted form of `value`.
+    ///
+    /// Struct values cannot be hashed and are always masked.
+    pub fn apply(&self, value: &EvaluationContextFieldValue) -> EvaluationContextFieldValue {
+        if *self == Self::Mask {
+            return REDACTED.into();
+        }
+
+        let canonical = match value {
+            EvaluationContextFieldValue::Bool(value) => value.to_string(),
+            EvaluationContextFieldValue::Int(value) => value.to_string(),
+            EvaluationContextFieldValue::Float(value) => format!("{value:?}"),
+            EvaluationContextFieldValue::String(value) => value.clone(),
+            EvaluationContextFieldValue::DateTime(value) => {
+                value.unix_timestamp_nanos().to_string()
+            }
+            EvaluationContextFieldValue::Struct(_) => return REDACTED.into(),
+        };
+
+        self.apply_str(&canonical).into()
+    }
+
+    /// Return the redacted form of the string `value`, as [`Redaction::apply`] would.
+    pub(crate) fn apply_str(&self, value: &str) -> String {
+        let Self::Hash { salt } = self else {
+            return REDACTED.to_string();
+        };
+
+        let digest = Sha256::new()
+            .chain_update(salt.as_bytes())
+            .chain_update(value.as_bytes())
+            .finalize();
+
+        digest
+            .iter()
+            .fold(String::with_capacity(64), |mut hex, byte| {
+                let _ = write!(hex, "{byte:02x}");
+                hex
+            })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn mask() {
+        assert_eq!(
+            Redaction::Mask.apply(&"secret".into()),
+            EvaluationContextFieldValue::String(REDACTED.to_string())
+        );
+    }
+
+    #[test]
+    fn hash_is_salted_and_stable() {
+        let first = Redaction::hash("salt").apply(&"secret".into());
+        let second = Redaction::hash("salt").apply(&"secret".into());
+        let other_salt = Redaction::hash("pepper").apply(&"secret".into());
+
+        assert_eq!(first, second);
+        assert_ne!(first, other_salt);
+        assert_eq!(first.as_str().unwrap().len(), 64);
+        assert!(!first.as_str().unwrap().contains("secret"));
+    }
+
+    #[test]
+    fn hash_masks_struct() {
+        assert_eq!(
+            Redaction::hash("salt").apply(&EvaluationContextFieldValue::new_struct(100)),
+            EvaluationContextFieldValue::String(REDACTED.to_string())
+        );
+    }
+}
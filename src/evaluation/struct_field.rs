@@ -0,0 +1,382 @@
+use std::{
+    error::Error as StdError,
+    fmt::{Display, Formatter},
+};
+
+use crate::{EvaluationError, EvaluationErrorCode, StructValue, Type, Value};
+
+// ============================================================
+//  StructField
+// ============================================================
+
+/// The types of the fields of a struct deriving `FlagStruct`.
+///
+/// Implemented for the primitive types of [`Value`], [`Vec`] and [`Option`] of them, and for
+/// every struct deriving `FlagStruct`, so structs nest. The elements of a [`Vec`] are
+/// [`StructElement`]s, so they cannot be optional.
+pub trait StructField: Sized {
+    /// Convert the value of a field, [`None`] when the field is missing.
+    fn from_field(value: Option<Value>) -> Result<Self, StructFieldError>;
+
+    /// Convert `self` to the value of a field, [`None`] to omit the field.
+    fn into_field(self) -> Option<Value>;
+}
+
+/// The types of the elements of a [`Vec`] field: every [`StructField`] but [`Option`], as an
+/// array cannot hold a missing element.
+///
+/// ```compile_fail
+/// use open_feature::StructField;
+///
+/// let _ = vec![Some(1), None].into_field();
+/// ```
+#[diagnostic::on_unimplemented(
+    message = "`{Self}` cannot be the element of a `Vec` field",
+    note = "an array cannot hold a missing element, use an `Option<Vec<_>>` field instead"
+)]
+pub trait StructElement: StructField {
+    /// Convert `self` to the value of an array element.
+    fn into_element(self) -> Value;
+}
+
+macro_rules! impl_struct_field {
+    ($type:ty, $expected:expr, $value:pat => $convert:expr) => {
+        impl StructField for $type {
+            fn from_field(value: Option<Value>) -> Result<Self, StructFieldError> {
+                match value {
+                    Some($value) => $convert,
+                    Some(value) => Err(StructFieldError::type_mismatch(&$expected, &value)),
+                    None => Err(StructFieldError::missing()),
+                }
+            }
+
+            fn into_field(self) -> Option<Value> {
+                Some(self.into())
+            }
+        }
+
+        impl StructElement for $type {
+            fn into_element(self) -> Value {
+                self.into()
+            }
+        }
+    };
+}
+
+macro_rules! impl_int_struct_field {
+    ($($type:ty),*) => {
+        $(
+            impl_struct_field!($type, Type::Int, Value::Int(value) => {
+                <$type>::try_from(value).map_err(|_| {
+                    StructFieldError::new(format!(
+                        "{value} is out of the range of {}",
+                        stringify!($type)
+                    ))
+                })
+            });
+        )*
+    };
+}
+
+impl_struct_field!(bool, Type::Bool, Value::Bool(value) => Ok(value));
+impl_struct_field!(i64, Type::Int, Value::Int(value) => Ok(value));
+impl_int_struct_field!(i8, i16, i32, u8, u16, u32);
+impl_struct_field!(String, Type::String, Value::String(value) => Ok(value));
+impl_struct_field!(StructValue, Type::Struct, Value::Struct(value) => Ok(value));
+
+impl StructField for f64 {
+    fn from_field(value: Option<Value>) -> Result<Self, StructFieldError> {
+        match value {
+            // INFO: Widen only the ints an f64 represents exactly
+            Some(value) => value
+                .as_f64_widened()
+                .ok_or_else(|| StructFieldError::type_mismatch(&Type::Float, &value)),
+            None => Err(StructFieldError::missing()),
+        }
+    }
+
+    fn into_field(self) -> Option<Value> {
+        Some(self.into())
+    }
+}
+
+impl StructElement for f64 {
+    fn into_element(self) -> Value {
+        self.into()
+    }
+}
+
+impl StructField for Value {
+    fn from_field(value: Option<Value>) -> Result<Self, StructFieldError> {
+        value.ok_or_else(StructFieldError::missing)
+    }
+
+    fn into_field(self) -> Option<Value> {
+        Some(self)
+    }
+}
+
+impl StructElement for Value {
+    fn into_element(self) -> Value {
+        self
+    }
+}
+
+impl<T: StructElement> StructField for Vec<T> {
+    fn from_field(value: Option<Value>) -> Result<Self, StructFieldError> {
+        match value {
+            Some(Value::Array(values)) => values
+                .into_iter()
+                .enumerate()
+                .map(|(index, value)| {
+                    T::from_field(Some(value)).map_err(|error| error.in_field(format!("[{index}]")))
+                })
+                .collect(),
+            Some(value) => Err(StructFieldError::type_mismatch(&Type::Array, &value)),
+            None => Err(StructFieldError::missing()),
+        }
+    }
+
+    fn into_field(self) -> Option<Value> {
+        Some(self.into_element())
+    }
+}
+
+impl<T: StructElement> StructElement for Vec<T> {
+    fn into_element(self) -> Value {
+        Value::Array(self.into_iter().map(T::into_element).collect())
+    }
+}
+
+impl<T: StructField> StructField for Option<T> {
+    fn from_field(value: Option<Value>) -> Result<Self, StructFieldError> {
+        value.map(|value| T::from_field(Some(value))).transpose()
+    }
+
+    fn into_field(self) -> Option<Value> {
+        self.and_then(T::into_field)
+    }
+}
+
+// ============================================================
+//  StructFieldError
+// ============================================================
+
+/// The error converting a [`StructValue`] to a struct deriving `FlagStruct`, naming the
+/// offending field.
+#[derive(Clone, Eq, PartialEq, Debug)]
+pub struct StructFieldError {
+    /// The path of the offending field, e.g. `banner.links[1].url`.
+    pub field: String,
+
+    /// What is wrong with the field.
+    pub message: String,
+}
+
+impl StructFieldError {
+    /// Create an error with given `message`, for the field it is returned from.
+    pub fn new(message: impl Into<String>) -> Self {
+        Self {
+            field: String::new(),
+            message: message.into(),
+        }
+    }
+
+    /// Create the error of a missing field.
+    pub fn missing() -> Self {
+        Self::new("missing")
+    }
+
+    /// Create the error of a field holding `value` instead of an `expected` value.
+    pub fn type_mismatch(expected: &Type, value: &Value) -> Self {
+        Self::new(format!(
+            "expected {expected} but resolved {}",
+            value.get_type()
+        ))
+    }
+
+    /// Prefix the path of the offending field with its parent `field`.
+    #[must_use]
+    pub fn in_field(mut self, field: impl Into<String>) -> Self {
+        let mut parent = field.into();
+
+        if !self.field.is_empty() {
+            if !self.field.starts_with('[') {
+                parent.push('.');
+            }
+            parent.push_str(&self.field);
+        }
+
+        self.field = parent;
+        self
+    }
+}
+
+impl Display for StructFieldError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        if self.field.is_empty() {
+            write!(f, "{}", self.message)
+        } else {
+            write!(f, "Field `{}`: {}", self.field, self.message)
+        }
+    }
+}
+
+impl StdError for StructFieldError {}
+
+impl From<StructFieldError> for EvaluationError {
+    fn from(error: StructFieldError) -> Self {
+        EvaluationError::builder()
+            .code(EvaluationErrorCode::TypeMismatch)
+            .message(error.to_string())
+            .build()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use open_feature_derive::FlagStruct;
+
+    use super::*;
+
+    #[derive(FlagStruct, Clone, PartialEq, Debug)]
+    struct Banner {
+        title: String,
+        #[flag(rename = "maxImpressions")]
+        max_impressions: u32,
+        #[flag(default)]
+        dismissible: bool,
+        #[flag(default = "default_color")]
+        color: String,
+        links: Vec<Link>,
+        theme: Option<Theme>,
+    }
+
+    #[derive(FlagStruct, Clone, PartialEq, Debug)]
+    struct Link {
+        url: String,
+        weight: f64,
+    }
+
+    #[derive(FlagStruct, Clone, PartialEq, Debug)]
+    struct Theme {
+        dark: bool,
+    }
+
+    fn default_color() -> String {
+        "blue".to_string()
+    }
+
+    fn banner() -> StructValue {
+        StructValue::default()
+            .with_field("title", "Sale")
+            .with_field("maxImpressions", 3)
+            .with_field(
+                "links",
+                vec![
+                    StructValue::default()
+                        .with_field("url", "/sale")
+                        .with_field("weight", 1),
+                    StructValue::default()
+                        .with_field("url", "/shoes")
+                        .with_field("weight", 0.5),
+                ],
+            )
+    }
+
+    #[test]
+    fn convert_struct() {
+        let banner = Banner::try_from(banner()).unwrap();
+        assert_eq!(
+            banner,
+            Banner {
+                title: "Sale".to_string(),
+                max_impressions: 3,
+                dismissible: false,
+                color: "blue".to_string(),
+                links: vec![
+                    Link {
+                        url: "/sale".to_string(),
+                        weight: 1.0,
+                    },
+                    Link {
+                        url: "/shoes".to_string(),
+                        weight: 0.5,
+                    },
+                ],
+                theme: None,
+            }
+        );
+
+        let value = Value::from(Banner {
+            theme: Some(Theme { dark: true }),
+            ..banner.clone()
+        });
+        let fields = &value.as_struct().unwrap().fields;
+        assert_eq!(fields.get("maxImpressions"), Some(&Value::Int(3)));
+        assert_eq!(
+            fields.get("color"),
+            Some(&Value::String("blue".to_string()))
+        );
+        assert_eq!(
+            fields.get("theme"),
+            Some(&Value::Struct(
+                StructValue::default().with_field("dark", true)
+            ))
+        );
+        assert_eq!(
+            Banner::try_from(value.as_struct().unwrap().clone())
+                .unwrap()
+                .theme,
+            Some(Theme { dark: true })
+        );
+        assert!(!StructValue::from(banner).fields.contains_key("theme"));
+    }
+
+    #[test]
+    fn convert_vec_fields() {
+        assert_eq!(
+            vec![1, 2].into_field(),
+            Some(Value::Array(vec![Value::Int(1), Value::Int(2)]))
+        );
+        assert_eq!(
+            vec![vec![1], vec![]].into_field(),
+            Some(Value::Array(vec![
+                Value::Array(vec![Value::Int(1)]),
+                Value::Array(vec![])
+            ]))
+        );
+
+        assert_eq!(f64::from_field(Some(Value::Int(-3))), Ok(-3.0));
+        assert_eq!(
+            f64::from_field(Some(Value::Int(i64::MIN))).unwrap_err(),
+            StructFieldError::type_mismatch(&Type::Float, &Value::Int(i64::MIN))
+        );
+    }
+
+    #[test]
+    fn name_offending_field() {
+        let error = |value: StructValue| Banner::try_from(value).unwrap_err().to_string();
+
+        assert_eq!(error(StructValue::default()), "Field `title`: missing");
+        assert_eq!(
+            error(banner().with_field("maxImpressions", -1)),
+            "Field `maxImpressions`: -1 is out of the range of u32"
+        );
+        assert_eq!(
+            error(banner().with_field("theme", StructValue::default().with_field("dark", 1))),
+            "Field `theme.dark`: expected bool but resolved int"
+        );
+        assert_eq!(
+            error(banner().with_field(
+                "links",
+                vec![StructValue::default().with_field("url", "/sale")]
+            )),
+            "Field `links[0].weight`: missing"
+        );
+        assert_eq!(
+            EvaluationError::from(Banner::try_from(StructValue::default()).unwrap_err()).code,
+            EvaluationErrorCode::TypeMismatch
+        );
+    }
+}
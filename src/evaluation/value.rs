@@ -1,5 +1,8 @@
 use std::collections::HashMap;
 
+/// The largest integer magnitude an f64 represents exactly.
+const MAX_SAFE_INTEGER: i64 = (1 << f64::MANTISSA_DIGITS) - 1;
+
 /// Hold a value in the evaluation result of supported types.
 #[derive(Clone, PartialEq, Debug)]
 #[allow(missing_docs)]
@@ -73,6 +76,19 @@ impl Value {
         }
     }
 
+    /// Try to convert `self` to f64, widening the ints an f64 represents exactly.
+    pub(crate) fn as_f64_widened(&self) -> Option<f64> {
+        match self {
+            Self::Float(value) => Some(*value),
+            // INFO: `unsigned_abs` as the magnitude of i64::MIN does not fit in an i64
+            #[allow(clippy::cast_precision_loss)]
+            Self::Int(value) if value.unsigned_abs() <= MAX_SAFE_INTEGER.unsigned_abs() => {
+                Some(*value as f64)
+            }
+            _ => None,
+        }
+    }
+
     /// Return `true` if this is a string value.
     pub fn is_str(&self) -> bool {
         matches!(self, Self::String(_))
@@ -0,0 +1,241 @@
+use std::fmt::Write;
+
+#[cfg(feature = "serde_json")]
+use crate::Redaction;
+use crate::{EvaluationContext, EvaluationDetails, EvaluationError, Value};
+
+// ============================================================
+//  LogRecord
+// ============================================================
+
+/// The stage of the evaluation lifecycle a [`LogRecord`] was produced in.
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+#[allow(missing_docs)]
+pub enum LogStage {
+    Before,
+    After,
+    Error,
+}
+
+impl LogStage {
+    /// Return the message describing the stage, e.g. `"Before stage"`.
+    pub fn message(self) -> &'static str {
+        match self {
+            Self::Before => "Before stage",
+            Self::After => "After stage",
+            Self::Error => "Error stage",
+        }
+    }
+}
+
+/// Everything [`LoggingHook`](super::LoggingHook) knows about a stage, handed to a
+/// [`LogFormatter`].
+#[derive(Clone, Debug)]
+pub struct LogRecord<'a> {
+    /// The stage of the evaluation.
+    pub stage: LogStage,
+
+    /// The domain of the client.
+    pub domain: &'a str,
+
+    /// The name of the provider.
+    pub provider_name: &'a str,
+
+    /// The key of the evaluated flag.
+    pub flag_key: &'a str,
+
+    /// The default value of the evaluation.
+    pub default_value: Option<&'a Value>,
+
+    /// The evaluation details, set in the after stage only.
+    pub details: Option<&'a EvaluationDetails<Value>>,
+
+    /// The error, set in the error stage only.
+    pub error: Option<&'a EvaluationError>,
+
+    /// The evaluation context with private fields already redacted, set only when the hook is
+    /// configured to include it.
+    pub evaluation_context: Option<&'a EvaluationContext>,
+}
+
+// ============================================================
+//  LogFormatter
+// ============================================================
+
+/// Turn a [`LogRecord`] into the message of a log line.
+pub trait LogFormatter: Send + Sync + 'static {
+    /// Format `record`.
+    fn format(&self, record: &LogRecord<'_>) -> String;
+}
+
+/// The human-readable format, e.g.
+/// `After stage: domain=, provider_name=No-op Provider, flag_key=my_feature, ...`.
+#[derive(Clone, Copy, Default, Debug)]
+pub struct TextFormatter;
+
+impl LogFormatter for TextFormatter {
+    fn format(&self, record: &LogRecord<'_>) -> String {
+        let mut output = format!(
+            "{}: domain={}, provider_name={}, flag_key={}, default_value={:?}",
+            record.stage.message(),
+            record.domain,
+            record.provider_name,
+            record.flag_key,
+            record.default_value,
+        );
+
+        if let Some(details) = record.details {
+            let _ = write!(
+                output,
+                ", reason={:?}, variant={:?}, value={:?}",
+                details.reason, details.variant, details.value
+            );
+        }
+
+        if let Some(error) = record.error {
+            let _ = write!(output, ", error_message={:?}", error.message);
+        }
+
+        if let Some(evaluation_context) = record.evaluation_context {
+            let _ = write!(output, ", evaluation_context={evaluation_context:?}");
+        }
+
+        output
+    }
+}
+
+/// A single-line JSON object per record, with flag values and the evaluation context as native
+/// JSON values, e.g.
+/// `{"default_value":false,"domain":"","flag_key":"my_feature","message":"After stage",...}`.
+///
+/// Requires the `serde_json` feature.
+#[cfg(feature = "serde_json")]
+#[derive(Clone, Copy, Default, Debug)]
+pub struct JsonFormatter;
+
+#[cfg(feature = "serde_json")]
+impl LogFormatter for JsonFormatter {
+    fn format(&self, record: &LogRecord<'_>) -> String {
+        let mut object = serde_json::Map::new();
+        let mut insert = |key: &str, value: serde_json::Value| {
+            object.insert(key.to_string(), value);
+        };
+
+        insert("message", record.stage.message().into());
+        insert("domain", record.domain.into());
+        insert("provider_name", record.provider_name.into());
+        insert("flag_key", record.flag_key.into());
+        insert(
+            "default_value",
+            record
+                .default_value
+                .map_or(serde_json::Value::Null, Into::into),
+        );
+
+        if let Some(details) = record.details {
+            if let Some(reason) = &details.reason {
+                insert("reason", reason.to_string().into());
+            }
+            if let Some(variant) = &details.variant {
+                insert("variant", variant.as_str().into());
+            }
+            insert("value", (&details.value).into());
+        }
+
+        if let Some(error) = record.error {
+            insert("error_code", error.code.to_string().into());
+            if let Some(message) = &error.message {
+                insert("error_message", message.as_str().into());
+            }
+        }
+
+        // INFO: The context is already redacted, so the redaction has nothing left to apply to
+        if let Some(evaluation_context) = record.evaluation_context {
+            insert(
+                "evaluation_context",
+                evaluation_context.to_json(&Redaction::Mask),
+            );
+        }
+
+        serde_json::Value::Object(object).to_string()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{EvaluationReason, FlagMetadata};
+
+    use super::*;
+
+    fn record(stage: LogStage, default_value: &Value) -> LogRecord<'_> {
+        LogRecord {
+            stage,
+            domain: "domain",
+            provider_name: "Provider",
+            flag_key: "flag",
+            default_value: Some(default_value),
+            details: None,
+            error: None,
+            evaluation_context: None,
+        }
+    }
+
+    #[test]
+    fn text_format() {
+        let default_value = Value::Bool(false);
+        let details = EvaluationDetails {
+            flag_key: "flag".to_string(),
+            value: Value::Bool(true),
+            reason: Some(EvaluationReason::Static),
+            variant: Some("on".to_string()),
+            flag_metadata: FlagMetadata::default(),
+            error_code: None,
+            error_message: None,
+        };
+
+        let mut record = record(LogStage::After, &default_value);
+        record.details = Some(&details);
+
+        assert_eq!(
+            TextFormatter.format(&record),
+            "After stage: domain=domain, provider_name=Provider, flag_key=flag, \
+             default_value=Some(Bool(false)), reason=Some(Static), variant=Some(\"on\"), \
+             value=Bool(true)"
+        );
+    }
+
+    #[cfg(feature = "serde_json")]
+    #[test]
+    fn json_format() {
+        use crate::{EvaluationErrorCode, StructValue};
+
+        let default_value = Value::Struct(
+            StructValue::default()
+                .with_field("name", "Bob \"the\" builder")
+                .with_field("tags", Value::Array(vec![1.into(), 2.5.into()])),
+        );
+        let error = EvaluationError::builder()
+            .code(EvaluationErrorCode::FlagNotFound)
+            .message("Unknown flag")
+            .build();
+        let evaluation_context = EvaluationContext::default()
+            .with_targeting_key("user-1")
+            .with_custom_field("admin", true)
+            .with_custom_field("signup", time::OffsetDateTime::UNIX_EPOCH);
+
+        let mut record = record(LogStage::Error, &default_value);
+        record.error = Some(&error);
+        record.evaluation_context = Some(&evaluation_context);
+
+        assert_eq!(
+            JsonFormatter.format(&record),
+            concat!(
+                r#"{"default_value":{"name":"Bob \"the\" builder","tags":[1,2.5]},"#,
+                r#""domain":"domain","error_code":"FLAG_NOT_FOUND","error_message":"Unknown flag","#,
+                r#""evaluation_context":{"admin":true,"signup":"1970-01-01T00:00:00Z","#,
+                r#""targetingKey":"user-1"},"flag_key":"flag","message":"Error stage","#,
+                r#""provider_name":"Provider"}"#
+            )
+        );
+    }
+}
@@ -0,0 +1,321 @@
+use std::sync::{
+    atomic::{AtomicU64, Ordering},
+    Arc,
+};
+
+use log::Level;
+use typed_builder::TypedBuilder;
+
+use crate::{EvaluationContext, EvaluationDetails, EvaluationError, Redaction, Value};
+
+use super::{Hook, HookContext, HookHints};
+
+mod format;
+#[cfg(feature = "serde_json")]
+pub use format::JsonFormatter;
+pub use format::{LogFormatter, LogRecord, LogStage, TextFormatter};
+
+const SAMPLED_KEY: &str = "logging_hook.sampled";
+
+/// A hook that logs the evaluation lifecycle of a flag.
+/// See the [spec](https://github.com/open-feature/spec/blob/main/specification/appendix-a-included-utilities.md#logging-hook)
+///
+/// With the `structured-logging` feature, records are logged as key-values and the configured
+/// [`LogFormatter`] is ignored.
+///
+/// Use [`LoggingHook::builder`] to configure it:
+///
+/// ```
+/// use open_feature::{LoggingHook, TextFormatter};
+///
+/// let hook = LoggingHook::builder()
+///     .after_level(log::Level::Info)
+///     .skip_before()
+///     .formatter(TextFormatter)
+///     .sample_one_in(100)
+///     .build();
+/// ```
+#[derive(TypedBuilder)]
+pub struct LoggingHook {
+    /// Include the evaluation context, with private fields redacted, in the log output.
+    #[builder(default)]
+    pub(crate) include_evaluation_context: bool,
+
+    /// How private fields of the evaluation context are redacted. Masked by default.
+    #[builder(default)]
+    pub(crate) redaction: Redaction,
+
+    /// The level of the before stage logs. `Debug` by default.
+    #[builder(default = Level::Debug)]
+    before_level: Level,
+
+    /// The level of the after stage logs. `Debug` by default.
+    #[builder(default = Level::Debug)]
+    after_level: Level,
+
+    /// The level of the error stage logs. `Error` by default.
+    #[builder(default = Level::Error)]
+    error_level: Level,
+
+    /// Do not log the before stage.
+    #[builder(setter(strip_bool))]
+    skip_before: bool,
+
+    /// The formatter of the log messages, [`TextFormatter`] by default.
+    ///
+    /// It is not used with the `structured-logging` feature, where records are logged as
+    /// key-values instead.
+    #[builder(
+        default = Arc::new(TextFormatter),
+        setter(transform = |formatter: impl LogFormatter| Arc::new(formatter) as Arc<dyn LogFormatter>)
+    )]
+    formatter: Arc<dyn LogFormatter>,
+
+    /// Log only 1 in `n` evaluations, the first one included. Errors are always logged.
+    /// `1` (log everything) by default.
+    #[builder(default = 1)]
+    sample_one_in: u64,
+
+    #[builder(default, setter(skip))]
+    evaluations: AtomicU64,
+}
+
+impl Default for LoggingHook {
+    fn default() -> Self {
+        Self::builder().build()
+    }
+}
+
+impl LoggingHook {
+    fn record<'a>(
+        stage: LogStage,
+        context: &'a HookContext,
+        evaluation_context: Option<&'a EvaluationContext>,
+    ) -> LogRecord<'a> {
+        LogRecord {
+            stage,
+            domain: &context.client_metadata.name,
+            provider_name: &context.provider_metadata.name,
+            flag_key: context.flag_key,
+            default_value: context.default_value.as_ref(),
+            details: None,
+            error: None,
+            evaluation_context,
+        }
+    }
+
+    fn redacted_context(&self, context: &HookContext) -> Option<EvaluationContext> {
+        self.include_evaluation_context
+            .then(|| context.evaluation_context.redacted(&self.redaction))
+    }
+
+    /// Decide once per evaluation whether its successful stages are logged.
+    fn sample(&self, context: &HookContext) -> bool {
+        let sampled =
+            self.evaluations.fetch_add(1, Ordering::Relaxed) % self.sample_one_in.max(1) == 0;
+        context.hook_data().set(SAMPLED_KEY, sampled);
+        sampled
+    }
+
+    fn is_sampled(context: &HookContext) -> bool {
+        context
+            .hook_data()
+            .get::<bool>(SAMPLED_KEY)
+            .map_or(true, |sampled| *sampled)
+    }
+}
+
+#[async_trait::async_trait]
+impl Hook for LoggingHook {
+    async fn before<'a>(
+        &self,
+        context: &HookContext<'a>,
+        _: Option<&'a HookHints>,
+    ) -> Result<Option<EvaluationContext>, EvaluationError> {
+        if self.sample(context) && !self.skip_before {
+            let evaluation_context = self.redacted_context(context);
+            let record = Self::record(LogStage::Before, context, evaluation_context.as_ref());
+
+            Self::log(&*self.formatter, &record, self.before_level);
+        }
+
+        Ok(None)
+    }
+
+    async fn after<'a>(
+        &self,
+        context: &HookContext<'a>,
+        value: &EvaluationDetails<Value>,
+        _: Option<&'a HookHints>,
+    ) -> Result<(), EvaluationError> {
+        if Self::is_sampled(context) {
+            let evaluation_context = self.redacted_context(context);
+            let mut record = Self::record(LogStage::After, context, evaluation_context.as_ref());
+            record.details = Some(value);
+
+            Self::log(&*self.formatter, &record, self.after_level);
+        }
+
+        Ok(())
+    }
+
+    async fn error<'a>(
+        &self,
+        context: &HookContext<'a>,
+        error: &EvaluationError,
+        _: Option<&'a HookHints>,
+    ) {
+        let evaluation_context = self.redacted_context(context);
+        let mut record = Self::record(LogStage::Error, context, evaluation_context.as_ref());
+        record.error = Some(error);
+
+        Self::log(&*self.formatter, &record, self.error_level);
+    }
+}
+
+#[cfg(not(feature = "structured-logging"))]
+impl LoggingHook {
+    fn log(formatter: &dyn LogFormatter, record: &LogRecord, level: Level) {
+        log::log!(level, "{}", formatter.format(record));
+    }
+}
+
+#[cfg(feature = "structured-logging")]
+mod structured {
+    use super::*;
+    use log::{kv::Value as LogValue, Level, Record};
+
+    const DOMAIN_KEY: &str = "domain";
+    const PROVIDER_NAME_KEY: &str = "provider_name";
+    const FLAG_KEY_KEY: &str = "flag_key";
+    const DEFAULT_VALUE_KEY: &str = "default_value";
+    const EVALUATION_CONTEXT_KEY: &str = "evaluation_context";
+    const ERROR_MESSAGE_KEY: &str = "error_message";
+    const REASON_KEY: &str = "reason";
+    const VARIANT_KEY: &str = "variant";
+    const VALUE_KEY: &str = "value";
+
+    impl LoggingHook {
+        // INFO: Records are logged as key-values, so the formatter is not used
+        pub(super) fn log(_: &dyn LogFormatter, record: &LogRecord, level: Level) {
+            let mut kvs = vec![
+                (DOMAIN_KEY, LogValue::from_display(&record.domain)),
+                (
+                    PROVIDER_NAME_KEY,
+                    LogValue::from_display(&record.provider_name),
+                ),
+                (FLAG_KEY_KEY, LogValue::from_display(&record.flag_key)),
+                (
+                    DEFAULT_VALUE_KEY,
+                    LogValue::from_debug(&record.default_value),
+                ),
+            ];
+
+            if let Some(details) = record.details {
+                kvs.extend(evaluation_details_to_kvs(details));
+            }
+
+            if let Some(error) = record.error {
+                kvs.extend(error_to_kvs(error));
+            }
+
+            if let Some(evaluation_context) = &record.evaluation_context {
+                kvs.push((
+                    EVALUATION_CONTEXT_KEY,
+                    LogValue::from_debug(evaluation_context),
+                ));
+            }
+
+            let kvs = kvs.as_slice();
+
+            // Single statement to avoid borrowing issues
+            // See issue https://github.com/rust-lang/rust/issues/92698
+            log::logger().log(
+                &Record::builder()
+                    .args(format_args!("{}", record.stage.message()))
+                    .level(level)
+                    .target("open_feature")
+                    .module_path_static(Some(module_path!()))
+                    .file_static(Some(file!()))
+                    .line(Some(line!()))
+                    .key_values(&kvs)
+                    .build(),
+            );
+        }
+    }
+
+    fn evaluation_details_to_kvs<'a>(
+        details: &'a EvaluationDetails<Value>,
+    ) -> Vec<(&'static str, LogValue<'a>)> {
+        let kvs = vec![
+            (REASON_KEY, LogValue::from_debug(&details.reason)),
+            (VARIANT_KEY, LogValue::from_debug(&details.variant)),
+            (VALUE_KEY, LogValue::from_debug(&details.value)),
+        ];
+
+        kvs
+    }
+
+    fn error_to_kvs<'a>(error: &'a EvaluationError) -> Vec<(&'static str, LogValue<'a>)> {
+        let kvs = vec![(ERROR_MESSAGE_KEY, LogValue::from_debug(&error.message))];
+
+        kvs
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{provider::ProviderMetadata, ClientMetadata, Type};
+
+    use super::*;
+
+    #[test]
+    fn sample_successes() {
+        let hook = LoggingHook::builder().sample_one_in(3).build();
+        let evaluation_context = EvaluationContext::default();
+
+        let sampled: Vec<_> = (0..6)
+            .map(|_| {
+                let context = HookContext::new(
+                    "flag",
+                    Type::Bool,
+                    &evaluation_context,
+                    ProviderMetadata::default(),
+                    None,
+                    ClientMetadata::default(),
+                );
+
+                assert!(LoggingHook::is_sampled(&context));
+                let sampled = hook.sample(&context);
+                assert_eq!(LoggingHook::is_sampled(&context), sampled);
+
+                sampled
+            })
+            .collect();
+
+        assert_eq!(sampled, [true, false, false, true, false, false]);
+    }
+
+    #[test]
+    fn redact_included_context() {
+        let hook = LoggingHook::builder()
+            .include_evaluation_context(true)
+            .build();
+        let evaluation_context = EvaluationContext::default().with_private_field("email", "a@b.c");
+        let context = HookContext::new(
+            "flag",
+            Type::Bool,
+            &evaluation_context,
+            ProviderMetadata::default(),
+            None,
+            ClientMetadata::default(),
+        );
+
+        let redacted = hook.redacted_context(&context).unwrap();
+        assert_eq!(
+            redacted.custom_fields["email"].as_str(),
+            Some(crate::REDACTED)
+        );
+        assert!(LoggingHook::default().redacted_context(&context).is_none());
+    }
+}
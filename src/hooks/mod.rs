@@ -1,4 +1,11 @@
-use std::{collections::HashMap, ops::Deref, sync::Arc};
+use std::{
+    any::Any,
+    collections::HashMap,
+    fmt::{Debug, Formatter},
+    ops::Deref,
+    sync::{Arc, Mutex},
+    time::Duration,
+};
 
 use crate::{
     provider::ProviderMetadata, ClientMetadata, EvaluationContext, EvaluationDetails,
@@ -6,7 +13,22 @@ use crate::{
 };
 
 mod logging;
-pub use logging::LoggingHook;
+#[cfg(feature = "serde_json")]
+pub use logging::JsonFormatter;
+pub use logging::{LogFormatter, LogRecord, LogStage, LoggingHook, TextFormatter};
+
+mod sync;
+pub use sync::{FnHook, SyncHook};
+
+#[cfg(feature = "tracing")]
+mod tracing;
+#[cfg(feature = "tracing")]
+pub use tracing::TracingHook;
+
+#[cfg(feature = "otel")]
+mod otel;
+#[cfg(feature = "otel")]
+pub use otel::{MetricDimensions, OtelMetricsHook, OtelTracingHook};
 
 // ============================================================
 //  Hook
@@ -17,42 +39,60 @@ pub use logging::LoggingHook;
 ///
 /// https://github.com/open-feature/spec/blob/main/specification/sections/04-hooks.md
 #[cfg_attr(
-    feature = "test-util",
+    any(test, feature = "test-util"),
     mockall::automock,
     allow(clippy::ref_option_ref)
 )] // Specified lifetimes manually to make it work with mockall
 #[async_trait::async_trait]
 pub trait Hook: Send + Sync + 'static {
     /// This method is called before the flag evaluation.
+    ///
+    /// By default it does nothing and keeps the evaluation context as is.
+    #[allow(unused_variables)]
     async fn before<'a>(
         &self,
         context: &HookContext<'a>,
         hints: Option<&'a HookHints>,
-    ) -> Result<Option<EvaluationContext>, EvaluationError>;
+    ) -> Result<Option<EvaluationContext>, EvaluationError> {
+        Ok(None)
+    }
 
     /// This method is called after the successful flag evaluation.
+    ///
+    /// By default it does nothing.
+    #[allow(unused_variables)]
     async fn after<'a>(
         &self,
         context: &HookContext<'a>,
         details: &EvaluationDetails<Value>,
         hints: Option<&'a HookHints>,
-    ) -> Result<(), EvaluationError>;
+    ) -> Result<(), EvaluationError> {
+        Ok(())
+    }
 
     /// This method is called on error during flag evaluation or error in before hook or after hook.
+    ///
+    /// By default it does nothing.
+    #[allow(unused_variables)]
     async fn error<'a>(
         &self,
         context: &HookContext<'a>,
         error: &EvaluationError,
         hints: Option<&'a HookHints>,
-    );
+    ) {
+    }
 
     /// This method is called after the flag evaluation, regardless of the result.
+    ///
+    /// By default it does nothing.
+    #[allow(unused_variables)]
     async fn finally<'a>(
         &self,
         context: &HookContext<'a>,
         evaluation_details: &EvaluationDetails<Value>,
         hints: Option<&'a HookHints>,
-    );
+    ) {
+    }
 }
 
 // ============================================================
@@ -61,12 +101,39 @@ pub trait Hook: Send + Sync + 'static {
 
 #[allow(missing_docs)]
 #[derive(Clone)]
-pub struct HookWrapper(Arc<dyn Hook>);
+pub struct HookWrapper {
+    hook: Arc<dyn Hook>,
+    timeout: Option<Duration>,
+}
 
 impl HookWrapper {
     #[allow(missing_docs)]
     pub fn new(hook: impl Hook) -> Self {
-        Self(Arc::new(hook))
+        Self {
+            hook: Arc::new(hook),
+            timeout: None,
+        }
+    }
+
+    /// Limit the execution time of each stage of the hook.
+    ///
+    /// A `before` or `after` stage exceeding it fails the evaluation, like any other hook error.
+    /// An `error` or `finally` stage exceeding it is abandoned and the evaluation proceeds.
+    #[must_use]
+    pub fn with_timeout(mut self, timeout: Duration) -> Self {
+        self.timeout = Some(timeout);
+        self
+    }
+
+    /// Return the execution timeout of each stage of the hook, if any.
+    pub fn timeout(&self) -> Option<Duration> {
+        self.timeout
+    }
+}
+
+impl<T: Hook> From<T> for HookWrapper {
+    fn from(hook: T) -> Self {
+        Self::new(hook)
     }
 }
 
@@ -74,7 +141,7 @@ impl Deref for HookWrapper {
     type Target = dyn Hook;
 
     fn deref(&self) -> &Self::Target {
-        &*self.0
+        &*self.hook
     }
 }
 
@@ -94,7 +161,7 @@ pub struct HookHints {
 
 /// Context for hooks.
 #[allow(missing_docs)]
-#[derive(Clone, PartialEq, Debug)]
+#[derive(Clone, Debug)]
 pub struct HookContext<'a> {
     pub flag_key: &'a str,
     pub flag_type: Type,
@@ -102,11 +169,103 @@ pub struct HookContext<'a> {
     pub provider_metadata: ProviderMetadata,
     pub default_value: Option<Value>,
     pub client_metadata: ClientMetadata,
+    pub(crate) hook_data: HookData,
+}
+
+impl<'a> HookContext<'a> {
+    /// Create the context of the evaluation of `flag_key`, with empty hook data.
+    pub fn new(
+        flag_key: &'a str,
+        flag_type: Type,
+        evaluation_context: &'a EvaluationContext,
+        provider_metadata: ProviderMetadata,
+        default_value: Option<Value>,
+        client_metadata: ClientMetadata,
+    ) -> Self {
+        Self {
+            flag_key,
+            flag_type,
+            evaluation_context,
+            provider_metadata,
+            default_value,
+            client_metadata,
+            hook_data: HookData::default(),
+        }
+    }
+
+    /// Return the data of the running hook, kept across its stages.
+    pub fn hook_data(&self) -> &HookData {
+        &self.hook_data
+    }
+
+    pub(crate) fn with_hook_data(&self, hook_data: &HookData) -> Self {
+        Self {
+            hook_data: hook_data.clone(),
+            ..self.clone()
+        }
+    }
+}
+
+/// The hook data is not part of the comparison, as it is private to each hook.
+impl PartialEq for HookContext<'_> {
+    fn eq(&self, other: &Self) -> bool {
+        self.flag_key == other.flag_key
+            && self.flag_type == other.flag_type
+            && self.evaluation_context == other.evaluation_context
+            && self.provider_metadata == other.provider_metadata
+            && self.default_value == other.default_value
+            && self.client_metadata == other.client_metadata
+    }
+}
+
+// ============================================================
+//  HookData
+// ============================================================
+
+/// Arbitrary data a hook can store during one flag evaluation, and read back in its later stages.
+///
+/// Every hook gets its own instance for every evaluation, so the data is never shared between
+/// hooks or evaluations.
+#[derive(Clone, Default)]
+pub struct HookData {
+    values: Arc<Mutex<HashMap<String, Arc<dyn Any + Send + Sync>>>>,
+}
+
+impl HookData {
+    /// Store `value` under `key`, replacing the previous value if any.
+    pub fn set<T: Any + Send + Sync>(&self, key: impl Into<String>, value: T) {
+        self.values
+            .lock()
+            .unwrap()
+            .insert(key.into(), Arc::new(value));
+    }
+
+    /// Return the value stored under `key`, if any and if it is of type `T`.
+    pub fn get<T: Any + Send + Sync>(&self, key: &str) -> Option<Arc<T>> {
+        let value = self.values.lock().unwrap().get(key).cloned()?;
+        value.downcast().ok()
+    }
+
+    /// Remove and return the value stored under `key`, if any and if it is of type `T`.
+    pub fn remove<T: Any + Send + Sync>(&self, key: &str) -> Option<Arc<T>> {
+        let value = self.values.lock().unwrap().remove(key)?;
+        value.downcast().ok()
+    }
+}
+
+impl Debug for HookData {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        f.debug_set()
+            .entries(self.values.lock().unwrap().keys())
+            .finish()
+    }
 }
 
 #[cfg(test)]
 mod tests {
 
+    use std::sync::atomic::{AtomicUsize, Ordering};
+
     use spec::spec;
 
     use crate::{
@@ -137,6 +296,7 @@ mod tests {
             provider_metadata: ProviderMetadata::default(),
             default_value: Some(Value::Bool(true)),
             client_metadata: ClientMetadata::default(),
+            hook_data: HookData::default(),
         };
 
         assert_eq!(context.flag_key, "flag_key");
@@ -259,6 +419,7 @@ mod tests {
                     default_value: Some(Value::Bool(false)),
                     provider_metadata: ProviderMetadata::default(),
                     client_metadata: client_metadata.clone(),
+                    hook_data: HookData::default(),
                 };
 
                 assert_eq!(ctx, &hook_ctx_1);
@@ -283,6 +444,7 @@ mod tests {
                     default_value: Some(Value::Bool(false)),
                     provider_metadata: ProviderMetadata::default(),
                     client_metadata: client_metadata.clone(),
+                    hook_data: HookData::default(),
                 };
 
                 assert_eq!(ctx, &hook_ctx_1);
@@ -885,6 +1047,177 @@ mod tests {
     #[test]
     fn default_value_covered_by_implementing_default_trait() {}
 
+    #[tokio::test]
+    async fn provider_panic_reported_as_error() {
+        let mut mock_hook = MockHook::new();
+
+        let mut api = OpenFeature::default();
+        let mut client = api.create_client();
+        let mut mock_provider = MockFeatureProvider::default();
+
+        mock_provider.expect_hooks().return_const(vec![]);
+        mock_provider.expect_initialize().return_const(());
+        mock_provider
+            .expect_metadata()
+            .return_const(ProviderMetadata::default());
+        mock_provider
+            .expect_resolve_bool_value()
+            .returning(|_, _| panic!("provider failure"));
+
+        api.set_provider(mock_provider).await;
+        drop(api);
+
+        mock_hook.expect_before().returning(|_, _| Ok(None));
+        mock_hook.expect_after().never();
+        mock_hook
+            .expect_error()
+            .once()
+            .withf(|_, error, _| {
+                assert_eq!(
+                    error.code,
+                    EvaluationErrorCode::General("Provider panicked".to_string())
+                );
+                assert_eq!(error.message, Some("provider failure".to_string()));
+                true
+            })
+            .return_const(());
+        mock_hook
+            .expect_finally()
+            .once()
+            .withf(|_, details, _| details.reason == Some(EvaluationReason::Error))
+            .return_const(());
+
+        client = client.with_hook(mock_hook);
+
+        let result = client.get_bool_value("flag", None, None).await;
+
+        assert!(result.is_err());
+        assert!(!result.unwrap_or_default());
+    }
+
+    #[tokio::test]
+    async fn finally_hook_panic_runs_remaining_hooks() {
+        let mut mock_hook_1 = MockHook::new();
+        let mut mock_hook_2 = MockHook::new();
+
+        let mut api = OpenFeature::default();
+        let mut client = api.create_client();
+        let mut mock_provider = MockFeatureProvider::default();
+
+        mock_provider.expect_hooks().return_const(vec![]);
+        mock_provider.expect_initialize().return_const(());
+        mock_provider
+            .expect_metadata()
+            .return_const(ProviderMetadata::default());
+        mock_provider
+            .expect_resolve_bool_value()
+            .return_const(Ok(ResolutionDetails::new(true)));
+
+        api.set_provider(mock_provider).await;
+        drop(api);
+
+        mock_hook_1.expect_before().returning(|_, _| Ok(None));
+        mock_hook_1.expect_after().return_const(Ok(()));
+        mock_hook_1.expect_finally().once().return_const(());
+
+        // Hooks after the resolution run in reverse order, so this one panics first.
+        mock_hook_2.expect_before().returning(|_, _| Ok(None));
+        mock_hook_2.expect_after().return_const(Ok(()));
+        mock_hook_2
+            .expect_finally()
+            .once()
+            .returning(|_, _, _| panic!("finally failure"));
+
+        client = client.with_hook(mock_hook_1).with_hook(mock_hook_2);
+
+        assert_eq!(client.get_bool_value("flag", None, None).await, Ok(true));
+    }
+
+    struct SlowHook;
+
+    #[async_trait::async_trait]
+    impl Hook for SlowHook {
+        async fn before<'a>(
+            &self,
+            _: &HookContext<'a>,
+            _: Option<&'a HookHints>,
+        ) -> Result<Option<EvaluationContext>, EvaluationError> {
+            tokio::time::sleep(Duration::from_secs(10)).await;
+            Ok(None)
+        }
+    }
+
+    #[tokio::test]
+    async fn before_hook_timeout() {
+        let mut api = OpenFeature::default();
+        let mut mock_provider = MockFeatureProvider::default();
+
+        mock_provider.expect_hooks().return_const(vec![]);
+        mock_provider.expect_initialize().return_const(());
+        mock_provider
+            .expect_metadata()
+            .return_const(ProviderMetadata::default());
+        mock_provider.expect_resolve_bool_value().never();
+
+        api.set_provider(mock_provider).await;
+
+        let client = api
+            .create_client()
+            .with_hook(HookWrapper::new(SlowHook).with_timeout(Duration::from_millis(10)));
+
+        let result = client.get_bool_value("flag", None, None).await;
+
+        assert_eq!(
+            result.unwrap_err().code,
+            EvaluationErrorCode::General("Before hook timed out".to_string())
+        );
+    }
+
+    #[tokio::test]
+    async fn hook_data_per_hook() {
+        let mut api = OpenFeature::default();
+        let mut mock_provider = MockFeatureProvider::default();
+
+        mock_provider.expect_hooks().return_const(vec![]);
+        mock_provider.expect_initialize().return_const(());
+        mock_provider
+            .expect_metadata()
+            .return_const(ProviderMetadata::default());
+        mock_provider
+            .expect_resolve_bool_value()
+            .return_const(Ok(ResolutionDetails::new(true)));
+
+        api.set_provider(mock_provider).await;
+
+        // Panics in hooks are isolated, so count the successful checks instead.
+        let checks = Arc::new(AtomicUsize::new(0));
+        let hook = |name: &'static str| {
+            let checks = checks.clone();
+            FnHook::before_fn(move |context, _| {
+                assert!(context.hook_data().get::<&str>("owner").is_none());
+                context.hook_data().set("owner", name);
+                Ok(None)
+            })
+            .with_finally_fn(move |context, _, _| {
+                if context.hook_data().get::<&str>("owner").as_deref() == Some(&name)
+                    && context.hook_data().get::<i64>("owner").is_none()
+                {
+                    checks.fetch_add(1, Ordering::SeqCst);
+                }
+            })
+        };
+
+        let client = api
+            .create_client()
+            .with_hook(hook("first"))
+            .with_hook(hook("second"));
+
+        // Data is fresh for every evaluation, which the before stage asserts.
+        assert_eq!(client.get_bool_value("flag", None, None).await, Ok(true));
+        assert_eq!(client.get_bool_value("flag", None, None).await, Ok(true));
+        assert_eq!(checks.load(Ordering::SeqCst), 4);
+    }
+
     fn error<T>() -> Result<T, EvaluationError> {
         Err(EvaluationError {
             code: EvaluationErrorCode::General("error".to_string()),
@@ -0,0 +1,411 @@
+use std::time::Instant;
+
+use opentelemetry::{
+    metrics::{Counter, Histogram, Meter, UpDownCounter},
+    KeyValue,
+};
+
+use crate::{
+    EvaluationContext, EvaluationDetails, EvaluationError, Hook, HookContext, HookHints, Value,
+};
+
+use super::{
+    error_type_value, reason_value, ERROR_TYPE, FLAG_KEY, PROVIDER_NAME, RESULT_REASON,
+    RESULT_VARIANT,
+};
+
+const REQUESTS_TOTAL: &str = "feature_flag.evaluation_requests_total";
+const SUCCESS_TOTAL: &str = "feature_flag.evaluation_success_total";
+const ERROR_TOTAL: &str = "feature_flag.evaluation_error_total";
+const ACTIVE_COUNT: &str = "feature_flag.evaluation_active_count";
+const DURATION: &str = "feature_flag.evaluation_duration";
+
+const START_TIME_KEY: &str = "start_time";
+
+// ============================================================
+//  MetricDimensions
+// ============================================================
+
+/// The dimensions (attributes) recorded along with the metrics of [`OtelMetricsHook`].
+///
+/// All of them are enabled by default. Disable the high-cardinality ones, e.g. `flag_key` in
+/// applications with a lot of flags, to keep the number of time series under control.
+#[derive(Clone, Debug)]
+pub struct MetricDimensions {
+    /// Record `feature_flag.key` on all the metrics.
+    pub flag_key: bool,
+
+    /// Record `feature_flag.provider.name` on the success counter and the latency histogram.
+    pub provider_name: bool,
+
+    /// Record `feature_flag.result.variant` on the success counter.
+    pub variant: bool,
+
+    /// Record `feature_flag.result.reason` on the success counter.
+    pub reason: bool,
+
+    /// Record `error.type` on the error counter.
+    pub error_type: bool,
+}
+
+impl Default for MetricDimensions {
+    fn default() -> Self {
+        Self {
+            flag_key: true,
+            provider_name: true,
+            variant: true,
+            reason: true,
+            error_type: true,
+        }
+    }
+}
+
+// ============================================================
+//  OtelMetricsHook
+// ============================================================
+
+/// A hook that records flag evaluation metrics with OpenTelemetry:
+/// * `feature_flag.evaluation_requests_total`: the number of evaluations.
+/// * `feature_flag.evaluation_success_total`: the number of successful evaluations.
+/// * `feature_flag.evaluation_error_total`: the number of failed evaluations.
+/// * `feature_flag.evaluation_active_count`: the number of evaluations in progress.
+/// * `feature_flag.evaluation_duration`: the latency of evaluations in seconds, measured from the
+///   before stage to the finally stage of this hook.
+pub struct OtelMetricsHook {
+    requests: Counter<u64>,
+    success: Counter<u64>,
+    errors: Counter<u64>,
+    active: UpDownCounter<i64>,
+    duration: Histogram<f64>,
+
+    dimensions: MetricDimensions,
+}
+
+impl OtelMetricsHook {
+    /// Create the hook with instruments from given `meter`, recording all the dimensions.
+    pub fn new(meter: &Meter) -> Self {
+        Self {
+            requests: meter
+                .u64_counter(REQUESTS_TOTAL)
+                .with_description("The number of flag evaluation requests")
+                .build(),
+            success: meter
+                .u64_counter(SUCCESS_TOTAL)
+                .with_description("The number of successful flag evaluations")
+                .build(),
+            errors: meter
+                .u64_counter(ERROR_TOTAL)
+                .with_description("The number of failed flag evaluations")
+                .build(),
+            active: meter
+                .i64_up_down_counter(ACTIVE_COUNT)
+                .with_description("The number of flag evaluations in progress")
+                .build(),
+            duration: meter
+                .f64_histogram(DURATION)
+                .with_description("The duration of flag evaluations")
+                .with_unit("s")
+                .build(),
+            dimensions: MetricDimensions::default(),
+        }
+    }
+
+    /// Set the dimensions recorded along with the metrics.
+    #[must_use]
+    pub fn with_dimensions(mut self, dimensions: MetricDimensions) -> Self {
+        self.dimensions = dimensions;
+        self
+    }
+
+    fn flag_attributes(&self, context: &HookContext) -> Vec<KeyValue> {
+        let mut attributes = Vec::new();
+
+        if self.dimensions.flag_key {
+            attributes.push(KeyValue::new(FLAG_KEY, context.flag_key.to_string()));
+        }
+
+        attributes
+    }
+
+    fn provider_attributes(&self, context: &HookContext) -> Vec<KeyValue> {
+        let mut attributes = self.flag_attributes(context);
+
+        if self.dimensions.provider_name {
+            attributes.push(KeyValue::new(
+                PROVIDER_NAME,
+                context.provider_metadata.name.clone(),
+            ));
+        }
+
+        attributes
+    }
+}
+
+#[async_trait::async_trait]
+impl Hook for OtelMetricsHook {
+    async fn before<'a>(
+        &self,
+        context: &HookContext<'a>,
+        _: Option<&'a HookHints>,
+    ) -> Result<Option<EvaluationContext>, EvaluationError> {
+        let attributes = self.flag_attributes(context);
+
+        self.requests.add(1, &attributes);
+        self.active.add(1, &attributes);
+        context.hook_data().set(START_TIME_KEY, Instant::now());
+
+        Ok(None)
+    }
+
+    async fn finally<'a>(
+        &self,
+        context: &HookContext<'a>,
+        details: &EvaluationDetails<Value>,
+        _: Option<&'a HookHints>,
+    ) {
+        // INFO: Count in the finally stage only, as a failing after hook also runs the error stage
+        if let Some(code) = &details.error_code {
+            let mut attributes = self.flag_attributes(context);
+
+            if self.dimensions.error_type {
+                attributes.push(KeyValue::new(ERROR_TYPE, error_type_value(code)));
+            }
+
+            self.errors.add(1, &attributes);
+        } else {
+            let mut attributes = self.provider_attributes(context);
+
+            if self.dimensions.variant {
+                if let Some(variant) = &details.variant {
+                    attributes.push(KeyValue::new(RESULT_VARIANT, variant.clone()));
+                }
+            }
+            if self.dimensions.reason {
+                if let Some(reason) = &details.reason {
+                    attributes.push(KeyValue::new(RESULT_REASON, reason_value(reason)));
+                }
+            }
+
+            self.success.add(1, &attributes);
+        }
+
+        // INFO: The before stage of this hook is skipped if a previous before hook fails
+        let Some(start_time) = context.hook_data().remove::<Instant>(START_TIME_KEY) else {
+            return;
+        };
+
+        self.active.add(-1, &self.flag_attributes(context));
+        self.duration.record(
+            start_time.elapsed().as_secs_f64(),
+            &self.provider_attributes(context),
+        );
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use opentelemetry::metrics::MeterProvider;
+    use opentelemetry_sdk::metrics::{
+        data::{
+            AggregatedMetrics, HistogramDataPoint, Metric, MetricData, ResourceMetrics,
+            ScopeMetrics,
+        },
+        InMemoryMetricExporter, PeriodicReader, SdkMeterProvider,
+    };
+
+    use crate::{
+        hooks::MockHook,
+        provider::{MockFeatureProvider, ProviderMetadata, ResolutionDetails},
+        EvaluationErrorCode, EvaluationReason, OpenFeature,
+    };
+
+    use super::*;
+
+    fn meter_provider() -> (SdkMeterProvider, InMemoryMetricExporter) {
+        let exporter = InMemoryMetricExporter::default();
+        let meter_provider = SdkMeterProvider::builder()
+            .with_reader(PeriodicReader::builder(exporter.clone()).build())
+            .build();
+
+        (meter_provider, exporter)
+    }
+
+    fn mock_provider() -> MockFeatureProvider {
+        let mut provider = MockFeatureProvider::new();
+        provider.expect_initialize().returning(|_| {});
+        provider.expect_hooks().return_const(vec![]);
+        provider
+            .expect_metadata()
+            .return_const(ProviderMetadata::new("Test Provider"));
+        provider
+    }
+
+    async fn evaluate(dimensions: MetricDimensions) -> Vec<ResourceMetrics> {
+        let (meter_provider, exporter) = meter_provider();
+
+        let mut provider = mock_provider();
+        provider
+            .expect_resolve_bool_value()
+            .return_const(Ok(ResolutionDetails::builder()
+                .value(true)
+                .variant("on")
+                .reason(EvaluationReason::Static)
+                .build()));
+        provider
+            .expect_resolve_int_value()
+            .return_const(Err(EvaluationError::builder()
+                .code(EvaluationErrorCode::FlagNotFound)
+                .build()));
+
+        let mut api = OpenFeature::default();
+        api.set_provider(provider).await;
+
+        let hook = OtelMetricsHook::new(&meter_provider.meter("test")).with_dimensions(dimensions);
+        let client = api.create_client().with_hook(hook);
+
+        let _ = client.get_bool_value("bool-flag", None, None).await;
+        let _ = client.get_bool_value("bool-flag", None, None).await;
+        let _ = client.get_int_value("int-flag", None, None).await;
+
+        meter_provider.force_flush().unwrap();
+        exporter.get_finished_metrics().unwrap()
+    }
+
+    fn find_metric<'a>(metrics: &'a [ResourceMetrics], name: &str) -> &'a Metric {
+        metrics
+            .iter()
+            .flat_map(ResourceMetrics::scope_metrics)
+            .flat_map(ScopeMetrics::metrics)
+            .find(|metric| metric.name() == name)
+            .unwrap()
+    }
+
+    /// Return the data points of a sum metric as (attributes, value) pairs.
+    fn sum_points(metric: &Metric) -> Vec<(Vec<KeyValue>, i64)> {
+        match metric.data() {
+            AggregatedMetrics::U64(MetricData::Sum(sum)) => sum
+                .data_points()
+                .map(|point| {
+                    (
+                        point.attributes().cloned().collect(),
+                        i64::try_from(point.value()).unwrap(),
+                    )
+                })
+                .collect(),
+            AggregatedMetrics::I64(MetricData::Sum(sum)) => sum
+                .data_points()
+                .map(|point| (point.attributes().cloned().collect(), point.value()))
+                .collect(),
+            data => panic!("Unexpected metric data: {data:?}"),
+        }
+    }
+
+    fn has_attribute(attributes: &[KeyValue], key: &str, value: &str) -> bool {
+        attributes
+            .iter()
+            .any(|kv| kv.key.as_str() == key && kv.value.as_str() == value)
+    }
+
+    #[tokio::test]
+    async fn record_metrics() {
+        let metrics = evaluate(MetricDimensions::default()).await;
+
+        let requests = sum_points(find_metric(&metrics, REQUESTS_TOTAL));
+        assert_eq!(requests.len(), 2);
+        assert!(requests
+            .iter()
+            .any(|(attributes, value)| *value == 2
+                && has_attribute(attributes, FLAG_KEY, "bool-flag")));
+
+        let success = sum_points(find_metric(&metrics, SUCCESS_TOTAL));
+        assert_eq!(success.len(), 1);
+        let (attributes, value) = &success[0];
+        assert_eq!(*value, 2);
+        assert!(has_attribute(attributes, FLAG_KEY, "bool-flag"));
+        assert!(has_attribute(attributes, PROVIDER_NAME, "Test Provider"));
+        assert!(has_attribute(attributes, RESULT_VARIANT, "on"));
+        assert!(has_attribute(attributes, RESULT_REASON, "static"));
+
+        let errors = sum_points(find_metric(&metrics, ERROR_TOTAL));
+        assert_eq!(errors.len(), 1);
+        let (attributes, value) = &errors[0];
+        assert_eq!(*value, 1);
+        assert!(has_attribute(attributes, FLAG_KEY, "int-flag"));
+        assert!(has_attribute(attributes, ERROR_TYPE, "flag_not_found"));
+
+        let active = sum_points(find_metric(&metrics, ACTIVE_COUNT));
+        assert!(active.iter().all(|(_, value)| *value == 0));
+
+        match find_metric(&metrics, DURATION).data() {
+            AggregatedMetrics::F64(MetricData::Histogram(histogram)) => {
+                assert_eq!(
+                    histogram
+                        .data_points()
+                        .map(HistogramDataPoint::count)
+                        .sum::<u64>(),
+                    3
+                );
+            }
+            data => panic!("Unexpected metric data: {data:?}"),
+        }
+    }
+
+    #[tokio::test]
+    async fn record_metrics_without_flag_key() {
+        let metrics = evaluate(MetricDimensions {
+            flag_key: false,
+            ..Default::default()
+        })
+        .await;
+
+        let requests = sum_points(find_metric(&metrics, REQUESTS_TOTAL));
+        assert_eq!(requests, vec![(vec![], 3)]);
+
+        let success = sum_points(find_metric(&metrics, SUCCESS_TOTAL));
+        assert!(success
+            .iter()
+            .all(|(attributes, _)| attributes.iter().all(|kv| kv.key.as_str() != FLAG_KEY)));
+    }
+
+    #[tokio::test]
+    async fn record_failing_after_hook_as_error_only() {
+        let (meter_provider, exporter) = meter_provider();
+
+        let mut provider = mock_provider();
+        provider
+            .expect_resolve_bool_value()
+            .return_const(Ok(ResolutionDetails::new(true)));
+
+        let mut failing_hook = MockHook::new();
+        failing_hook.expect_before().returning(|_, _| Ok(None));
+        failing_hook.expect_after().returning(|_, _, _| {
+            Err(EvaluationError::builder()
+                .code(EvaluationErrorCode::General("Rejected".to_string()))
+                .build())
+        });
+        failing_hook.expect_error().return_const(());
+        failing_hook.expect_finally().return_const(());
+
+        let mut api = OpenFeature::default();
+        api.set_provider(provider).await;
+
+        let client = api
+            .create_client()
+            .with_hook(OtelMetricsHook::new(&meter_provider.meter("test")))
+            .with_hook(failing_hook);
+        let _ = client.get_bool_value("bool-flag", None, None).await;
+
+        meter_provider.force_flush().unwrap();
+        let metrics = exporter.get_finished_metrics().unwrap();
+
+        let errors = sum_points(find_metric(&metrics, ERROR_TOTAL));
+        assert_eq!(errors.len(), 1);
+        assert_eq!(errors[0].1, 1);
+        assert!(has_attribute(&errors[0].0, ERROR_TYPE, "general"));
+        assert!(!metrics
+            .iter()
+            .flat_map(ResourceMetrics::scope_metrics)
+            .flat_map(ScopeMetrics::metrics)
+            .any(|metric| metric.name() == SUCCESS_TOTAL));
+    }
+}
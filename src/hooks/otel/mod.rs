@@ -0,0 +1,47 @@
+use opentelemetry::{KeyValue, StringValue, Value as OtelValue};
+
+use crate::{EvaluationErrorCode, EvaluationReason, Value};
+
+mod metrics;
+pub use metrics::{MetricDimensions, OtelMetricsHook};
+
+mod trace;
+pub use trace::OtelTracingHook;
+
+// Attribute keys of the OpenTelemetry feature flag semantic conventions.
+// See https://opentelemetry.io/docs/specs/semconv/feature-flags/feature-flags-logs/
+const FLAG_KEY: &str = "feature_flag.key";
+const PROVIDER_NAME: &str = "feature_flag.provider.name";
+const RESULT_VARIANT: &str = "feature_flag.result.variant";
+const RESULT_VALUE: &str = "feature_flag.result.value";
+const RESULT_REASON: &str = "feature_flag.result.reason";
+const ERROR_TYPE: &str = "error.type";
+const ERROR_MESSAGE: &str = "error.message";
+const CONTEXT_ID: &str = "feature_flag.context.id";
+
+/// The well-known values are lowercase in the semantic conventions.
+fn reason_value(reason: &EvaluationReason) -> StringValue {
+    match reason {
+        EvaluationReason::Other(reason) => reason.clone().into(),
+        reason => reason.to_string().to_lowercase().into(),
+    }
+}
+
+fn error_type_value(code: &EvaluationErrorCode) -> StringValue {
+    match code {
+        EvaluationErrorCode::General(_) => "general".into(),
+        code => code.to_string().to_lowercase().into(),
+    }
+}
+
+fn value_attribute(value: &Value) -> KeyValue {
+    let value = match value {
+        Value::Bool(value) => OtelValue::Bool(*value),
+        Value::Int(value) => OtelValue::I64(*value),
+        Value::Float(value) => OtelValue::F64(*value),
+        Value::String(value) => OtelValue::String(value.clone().into()),
+        value => OtelValue::String(format!("{value:?}").into()),
+    };
+
+    KeyValue::new(RESULT_VALUE, value)
+}
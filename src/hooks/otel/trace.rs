@@ -0,0 +1,258 @@
+use opentelemetry::{trace::get_active_span, KeyValue};
+
+use crate::{EvaluationDetails, Hook, HookContext, HookHints, Redaction, Value};
+
+use super::{
+    error_type_value, reason_value, value_attribute, CONTEXT_ID, ERROR_MESSAGE, ERROR_TYPE,
+    FLAG_KEY, PROVIDER_NAME, RESULT_REASON, RESULT_VARIANT,
+};
+
+const EVALUATION_EVENT: &str = "feature_flag.evaluation";
+
+/// A hook that records a `feature_flag.evaluation` event on the current span, following the
+/// OpenTelemetry feature flag semantic conventions.
+///
+/// Every evaluation is recorded once in the finally stage, with the error type and message of
+/// failed ones. Nothing is recorded if there is no active span.
+///
+/// A targeting key marked as private is recorded redacted, masked unless set otherwise with
+/// [`OtelTracingHook::with_redaction`].
+#[derive(Clone, Default, Debug)]
+pub struct OtelTracingHook {
+    redaction: Redaction,
+}
+
+impl OtelTracingHook {
+    /// Set how a private targeting key is redacted.
+    #[must_use]
+    pub fn with_redaction(mut self, redaction: Redaction) -> Self {
+        self.redaction = redaction;
+        self
+    }
+}
+
+#[async_trait::async_trait]
+impl Hook for OtelTracingHook {
+    async fn finally<'a>(
+        &self,
+        context: &HookContext<'a>,
+        details: &EvaluationDetails<Value>,
+        _: Option<&'a HookHints>,
+    ) {
+        let mut attributes = common_attributes(context, &self.redaction);
+
+        attributes.push(value_attribute(&details.value));
+        if let Some(variant) = &details.variant {
+            attributes.push(KeyValue::new(RESULT_VARIANT, variant.clone()));
+        }
+        if let Some(reason) = &details.reason {
+            attributes.push(KeyValue::new(RESULT_REASON, reason_value(reason)));
+        }
+        if let Some(code) = &details.error_code {
+            attributes.push(KeyValue::new(ERROR_TYPE, error_type_value(code)));
+        }
+        if let Some(message) = &details.error_message {
+            attributes.push(KeyValue::new(ERROR_MESSAGE, message.clone()));
+        }
+
+        get_active_span(|span| span.add_event(EVALUATION_EVENT, attributes));
+    }
+}
+
+fn common_attributes(context: &HookContext, redaction: &Redaction) -> Vec<KeyValue> {
+    let mut attributes = vec![
+        KeyValue::new(FLAG_KEY, context.flag_key.to_string()),
+        KeyValue::new(PROVIDER_NAME, context.provider_metadata.name.clone()),
+    ];
+
+    if let Some(targeting_key) = context.evaluation_context.redacted_targeting_key(redaction) {
+        attributes.push(KeyValue::new(CONTEXT_ID, targeting_key));
+    }
+
+    attributes
+}
+
+#[cfg(test)]
+mod tests {
+    use opentelemetry::{
+        trace::{FutureExt, TraceContextExt, Tracer, TracerProvider},
+        Context, Value as OtelValue,
+    };
+    use opentelemetry_sdk::trace::{InMemorySpanExporter, SdkTracerProvider};
+
+    use crate::{
+        hooks::MockHook,
+        provider::{MockFeatureProvider, ProviderMetadata, ResolutionDetails},
+        EvaluationContext, EvaluationError, EvaluationErrorCode, EvaluationReason, OpenFeature,
+    };
+
+    use super::{super::RESULT_VALUE, *};
+
+    async fn evaluate_in_span(
+        provider: MockFeatureProvider,
+        context: &EvaluationContext,
+    ) -> Vec<KeyValue> {
+        evaluate_in_span_with_hook(provider, context, OtelTracingHook::default(), None).await
+    }
+
+    async fn evaluate_in_span_with_hook(
+        provider: MockFeatureProvider,
+        context: &EvaluationContext,
+        tracing_hook: OtelTracingHook,
+        hook: Option<MockHook>,
+    ) -> Vec<KeyValue> {
+        let exporter = InMemorySpanExporter::default();
+        let tracer_provider = SdkTracerProvider::builder()
+            .with_simple_exporter(exporter.clone())
+            .build();
+        let tracer = tracer_provider.tracer("test");
+
+        let mut api = OpenFeature::default();
+        api.set_provider(provider).await;
+        let mut client = api.create_client().with_hook(tracing_hook);
+        if let Some(hook) = hook {
+            client = client.with_hook(hook);
+        }
+
+        let span = tracer.start("evaluation");
+        let _ = client
+            .get_bool_value("flag", Some(context), None)
+            .with_context(Context::current_with_span(span))
+            .await;
+
+        let spans = exporter.get_finished_spans().unwrap();
+        assert_eq!(spans.len(), 1);
+
+        let events = &spans[0].events.events;
+        assert_eq!(events.len(), 1);
+        assert_eq!(events[0].name, EVALUATION_EVENT);
+
+        events[0].attributes.clone()
+    }
+
+    fn attribute<'a>(attributes: &'a [KeyValue], key: &str) -> Option<&'a OtelValue> {
+        attributes
+            .iter()
+            .find(|kv| kv.key.as_str() == key)
+            .map(|kv| &kv.value)
+    }
+
+    fn mock_provider() -> MockFeatureProvider {
+        let mut provider = MockFeatureProvider::new();
+        provider.expect_initialize().returning(|_| {});
+        provider.expect_hooks().return_const(vec![]);
+        provider
+            .expect_metadata()
+            .return_const(ProviderMetadata::new("Test Provider"));
+        provider
+    }
+
+    #[tokio::test]
+    async fn record_success() {
+        let mut provider = mock_provider();
+        provider
+            .expect_resolve_bool_value()
+            .return_const(Ok(ResolutionDetails::builder()
+                .value(true)
+                .variant("on")
+                .reason(EvaluationReason::TargetingMatch)
+                .build()));
+
+        let attributes = evaluate_in_span(
+            provider,
+            &EvaluationContext::default().with_targeting_key("user-1"),
+        )
+        .await;
+
+        assert_eq!(attribute(&attributes, FLAG_KEY), Some(&"flag".into()));
+        assert_eq!(
+            attribute(&attributes, PROVIDER_NAME),
+            Some(&"Test Provider".into())
+        );
+        assert_eq!(attribute(&attributes, RESULT_VARIANT), Some(&"on".into()));
+        assert_eq!(attribute(&attributes, RESULT_VALUE), Some(&true.into()));
+        assert_eq!(
+            attribute(&attributes, RESULT_REASON),
+            Some(&"targeting_match".into())
+        );
+        assert_eq!(attribute(&attributes, CONTEXT_ID), Some(&"user-1".into()));
+        assert_eq!(attribute(&attributes, ERROR_TYPE), None);
+    }
+
+    #[tokio::test]
+    async fn record_error() {
+        let mut provider = mock_provider();
+        provider
+            .expect_resolve_bool_value()
+            .return_const(Err(EvaluationError::builder()
+                .code(EvaluationErrorCode::FlagNotFound)
+                .message("Unknown flag")
+                .build()));
+
+        let attributes = evaluate_in_span(provider, &EvaluationContext::default()).await;
+
+        assert_eq!(attribute(&attributes, FLAG_KEY), Some(&"flag".into()));
+        assert_eq!(attribute(&attributes, RESULT_VALUE), Some(&false.into()));
+        assert_eq!(attribute(&attributes, RESULT_REASON), Some(&"error".into()));
+        assert_eq!(
+            attribute(&attributes, ERROR_TYPE),
+            Some(&"flag_not_found".into())
+        );
+        assert_eq!(
+            attribute(&attributes, ERROR_MESSAGE),
+            Some(&"Unknown flag".into())
+        );
+        assert_eq!(attribute(&attributes, CONTEXT_ID), None);
+    }
+
+    #[tokio::test]
+    async fn record_failing_after_hook_once() {
+        let mut provider = mock_provider();
+        provider
+            .expect_resolve_bool_value()
+            .return_const(Ok(ResolutionDetails::new(true)));
+
+        let mut hook = MockHook::new();
+        hook.expect_before().returning(|_, _| Ok(None));
+        hook.expect_after().returning(|_, _, _| {
+            Err(EvaluationError::builder()
+                .code(EvaluationErrorCode::General("Rejected".to_string()))
+                .build())
+        });
+        hook.expect_error().return_const(());
+        hook.expect_finally().return_const(());
+
+        let attributes = evaluate_in_span_with_hook(
+            provider,
+            &EvaluationContext::default(),
+            OtelTracingHook::default(),
+            Some(hook),
+        )
+        .await;
+
+        assert_eq!(attribute(&attributes, RESULT_VALUE), Some(&false.into()));
+        assert_eq!(attribute(&attributes, RESULT_REASON), Some(&"error".into()));
+        assert_eq!(attribute(&attributes, ERROR_TYPE), Some(&"general".into()));
+    }
+
+    #[tokio::test]
+    async fn redact_private_targeting_key() {
+        let mut provider = mock_provider();
+        provider
+            .expect_resolve_bool_value()
+            .return_const(Ok(ResolutionDetails::new(true)));
+
+        let attributes = evaluate_in_span_with_hook(
+            provider,
+            &EvaluationContext::default().with_private_targeting_key("user-1"),
+            OtelTracingHook::default().with_redaction(Redaction::hash("salt")),
+            None,
+        )
+        .await;
+
+        assert_eq!(
+            attribute(&attributes, CONTEXT_ID),
+            Some(&Redaction::hash("salt").apply_str("user-1").into())
+        );
+    }
+}
@@ -0,0 +1,370 @@
+use crate::{EvaluationContext, EvaluationDetails, EvaluationError, Value};
+
+use super::{Hook, HookContext, HookHints};
+
+// ============================================================
+//  SyncHook
+// ============================================================
+
+/// A synchronous counterpart of [`Hook`] for hooks that never need to `await`.
+///
+/// Every type implementing [`SyncHook`] implements [`Hook`] as well, so it can be registered
+/// anywhere a [`Hook`] is accepted. All the stages are optional.
+pub trait SyncHook: Send + Sync + 'static {
+    /// This method is called before the flag evaluation.
+    #[allow(unused_variables)]
+    fn before(
+        &self,
+        context: &HookContext<'_>,
+        hints: Option<&HookHints>,
+    ) -> Result<Option<EvaluationContext>, EvaluationError> {
+        Ok(None)
+    }
+
+    /// This method is called after the successful flag evaluation.
+    #[allow(unused_variables)]
+    fn after(
+        &self,
+        context: &HookContext<'_>,
+        details: &EvaluationDetails<Value>,
+        hints: Option<&HookHints>,
+    ) -> Result<(), EvaluationError> {
+        Ok(())
+    }
+
+    /// This method is called on error during flag evaluation or error in before hook or after hook.
+    #[allow(unused_variables)]
+    fn error(&self, context: &HookContext<'_>, error: &EvaluationError, hints: Option<&HookHints>) {
+    }
+
+    /// This method is called after the flag evaluation, regardless of the result.
+    #[allow(unused_variables)]
+    fn finally(
+        &self,
+        context: &HookContext<'_>,
+        evaluation_details: &EvaluationDetails<Value>,
+        hints: Option<&HookHints>,
+    ) {
+    }
+}
+
+#[async_trait::async_trait]
+impl<T: SyncHook> Hook for T {
+    async fn before<'a>(
+        &self,
+        context: &HookContext<'a>,
+        hints: Option<&'a HookHints>,
+    ) -> Result<Option<EvaluationContext>, EvaluationError> {
+        SyncHook::before(self, context, hints)
+    }
+
+    async fn after<'a>(
+        &self,
+        context: &HookContext<'a>,
+        details: &EvaluationDetails<Value>,
+        hints: Option<&'a HookHints>,
+    ) -> Result<(), EvaluationError> {
+        SyncHook::after(self, context, details, hints)
+    }
+
+    async fn error<'a>(
+        &self,
+        context: &HookContext<'a>,
+        error: &EvaluationError,
+        hints: Option<&'a HookHints>,
+    ) {
+        SyncHook::error(self, context, error, hints);
+    }
+
+    async fn finally<'a>(
+        &self,
+        context: &HookContext<'a>,
+        evaluation_details: &EvaluationDetails<Value>,
+        hints: Option<&'a HookHints>,
+    ) {
+        SyncHook::finally(self, context, evaluation_details, hints);
+    }
+}
+
+// ============================================================
+//  FnHook
+// ============================================================
+
+type BeforeFn = dyn Fn(&HookContext<'_>, Option<&HookHints>) -> Result<Option<EvaluationContext>, EvaluationError>
+    + Send
+    + Sync;
+
+type AfterFn = dyn Fn(
+        &HookContext<'_>,
+        &EvaluationDetails<Value>,
+        Option<&HookHints>,
+    ) -> Result<(), EvaluationError>
+    + Send
+    + Sync;
+
+type ErrorFn = dyn Fn(&HookContext<'_>, &EvaluationError, Option<&HookHints>) + Send + Sync;
+
+type FinallyFn =
+    dyn Fn(&HookContext<'_>, &EvaluationDetails<Value>, Option<&HookHints>) + Send + Sync;
+
+/// A hook built out of closures, one per stage.
+///
+/// Stages without a closure do nothing.
+///
+/// ```
+/// use open_feature::FnHook;
+///
+/// let hook = FnHook::before_fn(|context, _| {
+///     println!("Evaluating {}", context.flag_key);
+///     Ok(None)
+/// })
+/// .with_finally_fn(|context, details, _| {
+///     println!("Evaluated {} to {:?}", context.flag_key, details.value);
+/// });
+/// ```
+#[derive(Default)]
+pub struct FnHook {
+    before: Option<Box<BeforeFn>>,
+    after: Option<Box<AfterFn>>,
+    error: Option<Box<ErrorFn>>,
+    finally: Option<Box<FinallyFn>>,
+}
+
+impl FnHook {
+    /// Create a hook that runs `f` in the before stage.
+    pub fn before_fn<F>(f: F) -> Self
+    where
+        F: Fn(
+                &HookContext<'_>,
+                Option<&HookHints>,
+            ) -> Result<Option<EvaluationContext>, EvaluationError>
+            + Send
+            + Sync
+            + 'static,
+    {
+        Self::default().with_before_fn(f)
+    }
+
+    /// Create a hook that runs `f` in the after stage.
+    pub fn after_fn<F>(f: F) -> Self
+    where
+        F: Fn(
+                &HookContext<'_>,
+                &EvaluationDetails<Value>,
+                Option<&HookHints>,
+            ) -> Result<(), EvaluationError>
+            + Send
+            + Sync
+            + 'static,
+    {
+        Self::default().with_after_fn(f)
+    }
+
+    /// Create a hook that runs `f` in the error stage.
+    pub fn error_fn<F>(f: F) -> Self
+    where
+        F: Fn(&HookContext<'_>, &EvaluationError, Option<&HookHints>) + Send + Sync + 'static,
+    {
+        Self::default().with_error_fn(f)
+    }
+
+    /// Create a hook that runs `f` in the finally stage.
+    pub fn finally_fn<F>(f: F) -> Self
+    where
+        F: Fn(&HookContext<'_>, &EvaluationDetails<Value>, Option<&HookHints>)
+            + Send
+            + Sync
+            + 'static,
+    {
+        Self::default().with_finally_fn(f)
+    }
+
+    /// Run `f` in the before stage, replacing the previous closure if any.
+    #[must_use]
+    pub fn with_before_fn<F>(mut self, f: F) -> Self
+    where
+        F: Fn(
+                &HookContext<'_>,
+                Option<&HookHints>,
+            ) -> Result<Option<EvaluationContext>, EvaluationError>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.before = Some(Box::new(f));
+        self
+    }
+
+    /// Run `f` in the after stage, replacing the previous closure if any.
+    #[must_use]
+    pub fn with_after_fn<F>(mut self, f: F) -> Self
+    where
+        F: Fn(
+                &HookContext<'_>,
+                &EvaluationDetails<Value>,
+                Option<&HookHints>,
+            ) -> Result<(), EvaluationError>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.after = Some(Box::new(f));
+        self
+    }
+
+    /// Run `f` in the error stage, replacing the previous closure if any.
+    #[must_use]
+    pub fn with_error_fn<F>(mut self, f: F) -> Self
+    where
+        F: Fn(&HookContext<'_>, &EvaluationError, Option<&HookHints>) + Send + Sync + 'static,
+    {
+        self.error = Some(Box::new(f));
+        self
+    }
+
+    /// Run `f` in the finally stage, replacing the previous closure if any.
+    #[must_use]
+    pub fn with_finally_fn<F>(mut self, f: F) -> Self
+    where
+        F: Fn(&HookContext<'_>, &EvaluationDetails<Value>, Option<&HookHints>)
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.finally = Some(Box::new(f));
+        self
+    }
+}
+
+impl SyncHook for FnHook {
+    fn before(
+        &self,
+        context: &HookContext<'_>,
+        hints: Option<&HookHints>,
+    ) -> Result<Option<EvaluationContext>, EvaluationError> {
+        match &self.before {
+            Some(f) => f(context, hints),
+            None => Ok(None),
+        }
+    }
+
+    fn after(
+        &self,
+        context: &HookContext<'_>,
+        details: &EvaluationDetails<Value>,
+        hints: Option<&HookHints>,
+    ) -> Result<(), EvaluationError> {
+        match &self.after {
+            Some(f) => f(context, details, hints),
+            None => Ok(()),
+        }
+    }
+
+    fn error(&self, context: &HookContext<'_>, error: &EvaluationError, hints: Option<&HookHints>) {
+        if let Some(f) = &self.error {
+            f(context, error, hints);
+        }
+    }
+
+    fn finally(
+        &self,
+        context: &HookContext<'_>,
+        evaluation_details: &EvaluationDetails<Value>,
+        hints: Option<&HookHints>,
+    ) {
+        if let Some(f) = &self.finally {
+            f(context, evaluation_details, hints);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::{
+        atomic::{AtomicUsize, Ordering},
+        Arc,
+    };
+
+    use crate::{
+        provider::{MockFeatureProvider, ProviderMetadata, ResolutionDetails},
+        EvaluationErrorCode, OpenFeature,
+    };
+
+    use super::*;
+
+    struct BeforeOnlyHook;
+
+    impl SyncHook for BeforeOnlyHook {
+        fn before(
+            &self,
+            _: &HookContext<'_>,
+            _: Option<&HookHints>,
+        ) -> Result<Option<EvaluationContext>, EvaluationError> {
+            Ok(Some(
+                EvaluationContext::default().with_targeting_key("sync_hook"),
+            ))
+        }
+    }
+
+    #[tokio::test]
+    async fn sync_hook_as_hook() {
+        let mut provider = MockFeatureProvider::new();
+        provider.expect_initialize().returning(|_| {});
+        provider.expect_hooks().return_const(vec![]);
+        provider
+            .expect_metadata()
+            .return_const(ProviderMetadata::default());
+        provider
+            .expect_resolve_bool_value()
+            .withf(|_, ctx| ctx.targeting_key.as_deref() == Some("sync_hook"))
+            .return_const(Ok(ResolutionDetails::new(true)));
+
+        let mut api = OpenFeature::default();
+        api.set_provider(provider).await;
+
+        let client = api.create_client().with_hook(BeforeOnlyHook);
+
+        assert_eq!(client.get_bool_value("flag", None, None).await, Ok(true));
+    }
+
+    #[tokio::test]
+    async fn fn_hook_stages() {
+        let mut provider = MockFeatureProvider::new();
+        provider.expect_initialize().returning(|_| {});
+        provider.expect_hooks().return_const(vec![]);
+        provider
+            .expect_metadata()
+            .return_const(ProviderMetadata::default());
+        provider
+            .expect_resolve_int_value()
+            .return_const(Err(EvaluationError::builder()
+                .code(EvaluationErrorCode::FlagNotFound)
+                .build()));
+
+        let mut api = OpenFeature::default();
+        api.set_provider(provider).await;
+
+        let errors = Arc::new(AtomicUsize::new(0));
+        let finals = Arc::new(AtomicUsize::new(0));
+
+        let hook = {
+            let errors = errors.clone();
+            let finals = finals.clone();
+
+            FnHook::error_fn(move |_, error, _| {
+                assert_eq!(error.code, EvaluationErrorCode::FlagNotFound);
+                errors.fetch_add(1, Ordering::SeqCst);
+            })
+            .with_finally_fn(move |context, _, _| {
+                assert_eq!(context.flag_key, "flag");
+                finals.fetch_add(1, Ordering::SeqCst);
+            })
+        };
+
+        let client = api.create_client().with_hook(hook);
+
+        assert!(client.get_int_value("flag", None, None).await.is_err());
+        assert_eq!(errors.load(Ordering::SeqCst), 1);
+        assert_eq!(finals.load(Ordering::SeqCst), 1);
+    }
+}
@@ -0,0 +1,305 @@
+use ::tracing::{field, Level};
+
+use crate::{EvaluationContext, EvaluationDetails, EvaluationError, Redaction, Value};
+
+use super::{Hook, HookContext, HookHints};
+
+/// Emit an event at a level only known at runtime, as `tracing` macros require a constant one.
+macro_rules! event_at {
+    ($level:expr, $($args:tt)+) => {
+        match $level {
+            Level::ERROR => ::tracing::event!(target: "open_feature", Level::ERROR, $($args)+),
+            Level::WARN => ::tracing::event!(target: "open_feature", Level::WARN, $($args)+),
+            Level::INFO => ::tracing::event!(target: "open_feature", Level::INFO, $($args)+),
+            Level::DEBUG => ::tracing::event!(target: "open_feature", Level::DEBUG, $($args)+),
+            Level::TRACE => ::tracing::event!(target: "open_feature", Level::TRACE, $($args)+),
+        }
+    };
+}
+
+/// A hook that emits [`tracing`](https://docs.rs/tracing) events along the evaluation lifecycle
+/// of a flag, with typed fields.
+///
+/// Note that with the `tracing` feature enabled, every flag evaluation runs in a
+/// `feature_flag.evaluation` span at `DEBUG` level, so the events of this hook, as well as any
+/// event emitted by the provider, nest under it.
+#[derive(Clone, Debug)]
+pub struct TracingHook {
+    before_level: Option<Level>,
+    after_level: Option<Level>,
+    error_level: Option<Level>,
+    include_evaluation_context: bool,
+    redaction: Redaction,
+}
+
+impl Default for TracingHook {
+    fn default() -> Self {
+        Self {
+            before_level: Some(Level::DEBUG),
+            after_level: Some(Level::DEBUG),
+            error_level: Some(Level::ERROR),
+            include_evaluation_context: false,
+            redaction: Redaction::default(),
+        }
+    }
+}
+
+impl TracingHook {
+    /// Set the level of the before stage events, `None` to not emit them.
+    #[must_use]
+    pub fn with_before_level(mut self, level: Option<Level>) -> Self {
+        self.before_level = level;
+        self
+    }
+
+    /// Set the level of the after stage events, `None` to not emit them.
+    #[must_use]
+    pub fn with_after_level(mut self, level: Option<Level>) -> Self {
+        self.after_level = level;
+        self
+    }
+
+    /// Set the level of the error stage events, `None` to not emit them.
+    #[must_use]
+    pub fn with_error_level(mut self, level: Option<Level>) -> Self {
+        self.error_level = level;
+        self
+    }
+
+    /// Include the evaluation context in the events.
+    #[must_use]
+    pub fn with_evaluation_context(mut self, include_evaluation_context: bool) -> Self {
+        self.include_evaluation_context = include_evaluation_context;
+        self
+    }
+
+    /// Set how private fields of the evaluation context are redacted. Masked by default.
+    #[must_use]
+    pub fn with_redaction(mut self, redaction: Redaction) -> Self {
+        self.redaction = redaction;
+        self
+    }
+
+    fn evaluation_context(
+        &self,
+        context: &HookContext,
+    ) -> Option<field::DebugValue<EvaluationContext>> {
+        self.include_evaluation_context
+            .then(|| field::debug(context.evaluation_context.redacted(&self.redaction)))
+    }
+}
+
+#[async_trait::async_trait]
+impl Hook for TracingHook {
+    async fn before<'a>(
+        &self,
+        context: &HookContext<'a>,
+        _: Option<&'a HookHints>,
+    ) -> Result<Option<EvaluationContext>, EvaluationError> {
+        if let Some(level) = self.before_level {
+            event_at!(
+                level,
+                domain = %context.client_metadata.name,
+                provider_name = %context.provider_metadata.name,
+                flag_key = context.flag_key,
+                default_value = ?context.default_value,
+                evaluation_context = self.evaluation_context(context),
+                "Before stage"
+            );
+        }
+
+        Ok(None)
+    }
+
+    async fn after<'a>(
+        &self,
+        context: &HookContext<'a>,
+        details: &EvaluationDetails<Value>,
+        _: Option<&'a HookHints>,
+    ) -> Result<(), EvaluationError> {
+        if let Some(level) = self.after_level {
+            event_at!(
+                level,
+                domain = %context.client_metadata.name,
+                provider_name = %context.provider_metadata.name,
+                flag_key = context.flag_key,
+                default_value = ?context.default_value,
+                variant = details.variant.as_deref(),
+                reason = details.reason.as_ref().map(field::display),
+                value = ?details.value,
+                evaluation_context = self.evaluation_context(context),
+                "After stage"
+            );
+        }
+
+        Ok(())
+    }
+
+    async fn error<'a>(
+        &self,
+        context: &HookContext<'a>,
+        error: &EvaluationError,
+        _: Option<&'a HookHints>,
+    ) {
+        if let Some(level) = self.error_level {
+            event_at!(
+                level,
+                domain = %context.client_metadata.name,
+                provider_name = %context.provider_metadata.name,
+                flag_key = context.flag_key,
+                default_value = ?context.default_value,
+                error_code = %error.code,
+                error_message = error.message.as_deref(),
+                evaluation_context = self.evaluation_context(context),
+                "Error stage"
+            );
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::{
+        collections::HashMap,
+        sync::{Arc, Mutex},
+    };
+
+    use ::tracing::{
+        field::{Field, Visit},
+        instrument::WithSubscriber,
+        Event, Subscriber,
+    };
+    use tracing_subscriber::{layer::Context, prelude::*, registry::LookupSpan, Layer};
+
+    use crate::{
+        provider::{MockFeatureProvider, ProviderMetadata, ResolutionDetails},
+        EvaluationErrorCode, EvaluationReason, OpenFeature,
+    };
+
+    use super::*;
+
+    #[derive(Debug)]
+    struct CapturedEvent {
+        level: Level,
+        span: Option<String>,
+        fields: HashMap<String, String>,
+    }
+
+    #[derive(Clone, Default)]
+    struct CaptureLayer(Arc<Mutex<Vec<CapturedEvent>>>);
+
+    impl<S: Subscriber + for<'a> LookupSpan<'a>> Layer<S> for CaptureLayer {
+        fn on_event(&self, event: &Event<'_>, ctx: Context<'_, S>) {
+            struct Visitor<'a>(&'a mut HashMap<String, String>);
+
+            impl Visit for Visitor<'_> {
+                fn record_debug(&mut self, field: &Field, value: &dyn std::fmt::Debug) {
+                    self.0
+                        .insert(field.name().to_string(), format!("{value:?}"));
+                }
+            }
+
+            let mut fields = HashMap::new();
+            event.record(&mut Visitor(&mut fields));
+
+            self.0.lock().unwrap().push(CapturedEvent {
+                level: *event.metadata().level(),
+                span: ctx.event_span(event).map(|span| span.name().to_string()),
+                fields,
+            });
+        }
+    }
+
+    async fn evaluate(hook: TracingHook, provider: MockFeatureProvider) -> Vec<CapturedEvent> {
+        let layer = CaptureLayer::default();
+        let subscriber = tracing_subscriber::registry().with(layer.clone());
+
+        let mut api = OpenFeature::default();
+        api.set_provider(provider).await;
+        let client = api.create_named_client("domain").with_hook(hook);
+
+        let context = EvaluationContext::default()
+            .with_targeting_key("user-1")
+            .with_private_field("email", "user@example.com");
+        let _ = client
+            .get_bool_value("flag", Some(&context), None)
+            .with_subscriber(subscriber)
+            .await;
+
+        Arc::try_unwrap(layer.0).unwrap().into_inner().unwrap()
+    }
+
+    fn mock_provider() -> MockFeatureProvider {
+        let mut provider = MockFeatureProvider::new();
+        provider.expect_initialize().returning(|_| {});
+        provider.expect_hooks().return_const(vec![]);
+        provider
+            .expect_metadata()
+            .return_const(ProviderMetadata::new("Test Provider"));
+        provider
+    }
+
+    #[tokio::test]
+    async fn emit_events() {
+        let mut provider = mock_provider();
+        provider.expect_resolve_bool_value().returning(|_, _| {
+            ::tracing::info!("Resolving in provider");
+            Ok(ResolutionDetails::builder()
+                .value(true)
+                .variant("on")
+                .reason(EvaluationReason::Static)
+                .build())
+        });
+
+        let events = evaluate(TracingHook::default(), provider).await;
+        assert_eq!(events.len(), 3);
+
+        let before = &events[0];
+        assert_eq!(before.level, Level::DEBUG);
+        assert_eq!(before.fields["message"], "Before stage");
+        assert_eq!(before.fields["domain"], "domain");
+        assert_eq!(before.fields["provider_name"], "Test Provider");
+        assert_eq!(before.fields["flag_key"], "\"flag\"");
+        assert!(!before.fields.contains_key("evaluation_context"));
+
+        let provider_event = &events[1];
+        assert_eq!(provider_event.fields["message"], "Resolving in provider");
+
+        let after = &events[2];
+        assert_eq!(after.fields["message"], "After stage");
+        assert_eq!(after.fields["variant"], "\"on\"");
+        assert_eq!(after.fields["reason"], "STATIC");
+        assert_eq!(after.fields["value"], "Bool(true)");
+
+        assert!(events
+            .iter()
+            .all(|event| event.span.as_deref() == Some("feature_flag.evaluation")));
+    }
+
+    #[tokio::test]
+    async fn emit_error_events_with_context() {
+        let mut provider = mock_provider();
+        provider
+            .expect_resolve_bool_value()
+            .return_const(Err(EvaluationError::builder()
+                .code(EvaluationErrorCode::FlagNotFound)
+                .message("Unknown flag")
+                .build()));
+
+        let hook = TracingHook::default()
+            .with_before_level(None)
+            .with_error_level(Some(Level::WARN))
+            .with_evaluation_context(true);
+
+        let events = evaluate(hook, provider).await;
+        assert_eq!(events.len(), 1);
+
+        let error = &events[0];
+        assert_eq!(error.level, Level::WARN);
+        assert_eq!(error.fields["message"], "Error stage");
+        assert_eq!(error.fields["error_code"], "FLAG_NOT_FOUND");
+        assert_eq!(error.fields["error_message"], "\"Unknown flag\"");
+        assert!(error.fields["evaluation_context"].contains("user-1"));
+        assert!(!error.fields["evaluation_context"].contains("user@example.com"));
+    }
+}
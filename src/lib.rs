@@ -12,6 +12,23 @@
 #![allow(clippy::must_use_candidate)]
 #![allow(clippy::struct_excessive_bools)]
 #![allow(clippy::too_many_lines)]
+// INFO: Lints the unit tests are not held to, e.g. comparing floats or writing out bools
+#![cfg_attr(
+    test,
+    allow(
+        clippy::approx_constant,
+        clippy::bool_assert_comparison,
+        clippy::clone_on_copy,
+        clippy::float_cmp,
+        clippy::implicit_clone,
+        clippy::let_underscore_future,
+        clippy::semicolon_if_nothing_returned,
+        clippy::unnecessary_semicolon
+    )
+)]
+
+// INFO: Lets the code generated by the derive macros name the crate from inside it
+extern crate self as open_feature;
 
 /// The OpenFeature API and client.
 mod api;
@@ -29,6 +46,18 @@ pub use hooks::*;
 pub mod provider;
 pub use async_trait::async_trait;
 
+/// The channel of [`provider::FeatureProvider::subscribe_events`], re-exported so providers
+/// build their events on the same version.
+pub use async_broadcast;
+
+/// Derive `TryFrom<StructValue>` and `Into<Value>` for a struct, see [`StructField`].
+#[cfg(feature = "derive")]
+pub use open_feature_derive::FlagStruct;
+
+/// Derive `From<T>` for [`EvaluationContext`] for a struct, see [`ContextField`].
+#[cfg(feature = "derive")]
+pub use open_feature_derive::IntoEvaluationContext;
+
 /// Optional support for [`serde_json::Value`].
 #[cfg(feature = "serde_json")]
 pub mod serde_json;
@@ -0,0 +1,28 @@
+// ============================================================
+//  ProviderEvent
+// ============================================================
+
+/// An event emitted by a provider, see
+/// [`FeatureProvider::subscribe_events`](super::FeatureProvider::subscribe_events).
+///
+/// See the [spec](https://openfeature.dev/specification/sections/events).
+#[derive(Clone, PartialEq, Eq, Debug)]
+#[non_exhaustive]
+pub enum ProviderEvent {
+    /// The flag configuration of the provider changed.
+    ConfigurationChanged {
+        /// The keys of the changed flags, `None` if any flag may have changed.
+        flags_changed: Option<Vec<String>>,
+    },
+}
+
+impl ProviderEvent {
+    /// Return `true` if the event may affect the value of `flag_key`.
+    pub fn affects(&self, flag_key: &str) -> bool {
+        match self {
+            Self::ConfigurationChanged { flags_changed } => flags_changed
+                .as_ref()
+                .map_or(true, |flags| flags.iter().any(|flag| flag == flag_key)),
+        }
+    }
+}
@@ -1,8 +1,11 @@
+use std::collections::HashMap;
+
+use async_broadcast::Receiver;
 use async_trait::async_trait;
 
-use crate::{EvaluationContext, EvaluationResult, StructValue};
+use crate::{EvaluationContext, EvaluationResult, StructValue, Value};
 
-use super::ResolutionDetails;
+use super::{FlagDefinition, ProviderEvent, ResolutionDetails};
 
 // ============================================================
 //  FeatureProvider
@@ -21,7 +24,6 @@ use super::ResolutionDetails;
 /// vendor SDK, embed an REST client, or read flags from a local file.
 ///
 /// See the [spec](https://openfeature.dev/specification/sections/providers).
-#[cfg_attr(feature = "test-util", mockall::automock)]
 #[async_trait]
 pub trait FeatureProvider: Send + Sync + 'static {
     /// The provider MAY define an initialize function which accepts the global evaluation
@@ -37,6 +39,21 @@ pub trait FeatureProvider: Send + Sync + 'static {
     #[allow(unused_variables)]
     async fn initialize(&mut self, context: &EvaluationContext) {}
 
+    /// The provider MAY define an on context changed function, which takes an argument for the
+    /// previous context and the newly set context, in order to respond to an evaluation context
+    /// change. It is mainly meant for providers of the static context paradigm, caching the
+    /// flags resolved for the context of the one user of the application.
+    ///
+    /// It is called every time the global evaluation context is set, and the provider is reported
+    /// as RECONCILING until it returns.
+    #[allow(unused_variables)]
+    async fn on_context_changed(
+        &self,
+        old_context: &EvaluationContext,
+        new_context: &EvaluationContext,
+    ) {
+    }
+
     /// The provider MAY define a status field/accessor which indicates the readiness of the
     /// provider, with possible values NOT_READY, READY, or ERROR.
     ///
@@ -55,6 +72,14 @@ pub trait FeatureProvider: Send + Sync + 'static {
         &[]
     }
 
+    /// The provider MAY emit events, e.g. when its flag configuration changes. Return a new
+    /// receiver of them, typically out of an [`async_broadcast::Sender`] owned by the provider.
+    ///
+    /// Providers without events return `None`, the default.
+    fn subscribe_events(&self) -> Option<Receiver<ProviderEvent>> {
+        None
+    }
+
     /// Resolve given `flag_key` as a bool value.
     async fn resolve_bool_value(
         &self,
@@ -89,6 +114,115 @@ pub trait FeatureProvider: Send + Sync + 'static {
         flag_key: &str,
         evaluation_context: &EvaluationContext,
     ) -> EvaluationResult<ResolutionDetails<StructValue>>;
+
+    /// The provider MAY resolve given `flag_key` synchronously, out of the state it cached for the
+    /// global evaluation context passed to [`FeatureProvider::initialize`] or
+    /// [`FeatureProvider::on_context_changed`].
+    ///
+    /// Return `None`, the default, if synchronous resolution is not supported.
+    #[allow(unused_variables)]
+    fn resolve_cached_value(
+        &self,
+        flag_key: &str,
+    ) -> Option<EvaluationResult<ResolutionDetails<Value>>> {
+        None
+    }
+
+    /// The provider MAY resolve all of its flags for `evaluation_context` at once, keyed by flag
+    /// key, so that batch evaluations make a single round-trip.
+    ///
+    /// Return `None`, the default, if bulk resolution is not supported, in which case flags are
+    /// resolved one by one.
+    #[allow(unused_variables)]
+    async fn resolve_all(
+        &self,
+        evaluation_context: &EvaluationContext,
+    ) -> Option<EvaluationResult<HashMap<String, ResolutionDetails<Value>>>> {
+        None
+    }
+
+    /// The provider MAY list the definitions of the flags it knows, e.g. for admin tooling or
+    /// stale flag detection.
+    ///
+    /// Return `None`, the default, if listing flags is not supported.
+    async fn list_flags(&self) -> Option<EvaluationResult<Vec<FlagDefinition>>> {
+        None
+    }
+
+    /// The provider MAY define a shutdown function to dispose of its resources, e.g. background
+    /// tasks or connections.
+    ///
+    /// It is called once the provider is replaced, superseded by a later registration, or
+    /// unbound by [`OpenFeature::shutdown`](crate::OpenFeature::shutdown). Evaluations already
+    /// in flight may still complete with it.
+    async fn shutdown(&self) {}
+}
+
+// INFO: Mocked by hand to leave out `shutdown`, called on every replaced provider
+#[cfg(any(test, feature = "test-util"))]
+mockall::mock! {
+    pub FeatureProvider {}
+
+    #[async_trait]
+    impl FeatureProvider for FeatureProvider {
+        async fn initialize(&mut self, context: &EvaluationContext);
+
+        async fn on_context_changed(
+            &self,
+            old_context: &EvaluationContext,
+            new_context: &EvaluationContext,
+        );
+
+        fn status(&self) -> ProviderStatus;
+
+        fn metadata(&self) -> &ProviderMetadata;
+
+        fn hooks(&self) -> &[crate::hooks::HookWrapper];
+
+        fn subscribe_events(&self) -> Option<Receiver<ProviderEvent>>;
+
+        async fn resolve_bool_value(
+            &self,
+            flag_key: &str,
+            evaluation_context: &EvaluationContext,
+        ) -> EvaluationResult<ResolutionDetails<bool>>;
+
+        async fn resolve_int_value(
+            &self,
+            flag_key: &str,
+            evaluation_context: &EvaluationContext,
+        ) -> EvaluationResult<ResolutionDetails<i64>>;
+
+        async fn resolve_float_value(
+            &self,
+            flag_key: &str,
+            evaluation_context: &EvaluationContext,
+        ) -> EvaluationResult<ResolutionDetails<f64>>;
+
+        async fn resolve_string_value(
+            &self,
+            flag_key: &str,
+            evaluation_context: &EvaluationContext,
+        ) -> EvaluationResult<ResolutionDetails<String>>;
+
+        async fn resolve_struct_value(
+            &self,
+            flag_key: &str,
+            evaluation_context: &EvaluationContext,
+        ) -> EvaluationResult<ResolutionDetails<StructValue>>;
+
+        fn resolve_cached_value(
+            &self,
+            flag_key: &str,
+        ) -> Option<EvaluationResult<ResolutionDetails<Value>>>;
+
+        async fn resolve_all(
+            &self,
+            evaluation_context: &EvaluationContext,
+        ) -> Option<EvaluationResult<HashMap<String, ResolutionDetails<Value>>>>;
+
+        async fn list_flags(&self) -> Option<EvaluationResult<Vec<FlagDefinition>>>;
+    }
 }
 
 // ============================================================
@@ -114,7 +248,7 @@ impl ProviderMetadata {
 // ============================================================
 
 /// The status of a feature provider.
-#[derive(Default, PartialEq, Eq, Debug)]
+#[derive(Clone, Copy, Default, PartialEq, Eq, Debug)]
 pub enum ProviderStatus {
     /// The provider has not been initialized.
     #[default]
@@ -129,4 +263,8 @@ pub enum ProviderStatus {
     /// The provider's cached state is no longer valid and may not be up-to-date with the source of
     /// truth.
     STALE,
+
+    /// The provider is reconciling its state with a new evaluation context, see
+    /// [`FeatureProvider::on_context_changed`].
+    Reconciling,
 }
@@ -0,0 +1,45 @@
+use std::collections::HashMap;
+
+use crate::{FlagMetadata, Type, Value};
+
+/// The definition of a flag, as listed by [`FeatureProvider::list_flags`](super::FeatureProvider::list_flags).
+#[derive(Clone, PartialEq, Debug)]
+pub struct FlagDefinition {
+    /// The key of the flag.
+    pub key: String,
+
+    /// The type of the values of the flag.
+    pub flag_type: Type,
+
+    /// The values the flag can resolve to, keyed by variant.
+    pub variants: HashMap<String, Value>,
+
+    /// The metadata of the flag.
+    pub flag_metadata: FlagMetadata,
+}
+
+impl FlagDefinition {
+    /// Create a new definition of flag `key` of type `flag_type`, without variants nor metadata.
+    pub fn new(key: impl Into<String>, flag_type: Type) -> Self {
+        Self {
+            key: key.into(),
+            flag_type,
+            variants: HashMap::new(),
+            flag_metadata: FlagMetadata::default(),
+        }
+    }
+
+    /// Add the variant `name` resolving to `value`.
+    #[must_use]
+    pub fn with_variant(mut self, name: impl Into<String>, value: impl Into<Value>) -> Self {
+        self.variants.insert(name.into(), value.into());
+        self
+    }
+
+    /// Set the metadata of the flag.
+    #[must_use]
+    pub fn with_flag_metadata(mut self, flag_metadata: FlagMetadata) -> Self {
+        self.flag_metadata = flag_metadata;
+        self
+    }
+}
@@ -2,11 +2,27 @@
 mod details;
 pub use details::ResolutionDetails;
 
+/// Provider events.
+mod events;
+pub use events::ProviderEvent;
+
+/// Flag definitions for introspection.
+mod flag_definition;
+pub use flag_definition::FlagDefinition;
+
 /// Feature provider trait.
 mod feature_provider;
-pub use feature_provider::{
-    FeatureProvider, MockFeatureProvider, ProviderMetadata, ProviderStatus,
-};
+#[cfg(any(test, feature = "test-util"))]
+pub use feature_provider::MockFeatureProvider;
+pub use feature_provider::{FeatureProvider, ProviderMetadata, ProviderStatus};
+
+/// Synchronous feature provider trait.
+mod sync_feature_provider;
+pub use sync_feature_provider::{SyncFeatureProvider, SyncProvider};
+
+/// Single-method feature provider trait.
+mod value_provider;
+pub use value_provider::ValueProvider;
 
 /// The default no-op provider.
 mod no_op_provider;
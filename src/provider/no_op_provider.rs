@@ -4,7 +4,7 @@ use crate::{
     EvaluationContext, EvaluationError, EvaluationErrorCode, EvaluationResult, StructValue,
 };
 
-use super::{FeatureProvider, ProviderMetadata, ProviderStatus, ResolutionDetails};
+use super::{FeatureProvider, FlagDefinition, ProviderMetadata, ProviderStatus, ResolutionDetails};
 
 // ============================================================
 //  NoOpProvider
@@ -75,6 +75,10 @@ impl FeatureProvider for NoOpProvider {
     ) -> Result<ResolutionDetails<StructValue>, EvaluationError> {
         just_error()
     }
+
+    async fn list_flags(&self) -> Option<EvaluationResult<Vec<FlagDefinition>>> {
+        Some(Ok(Vec::new()))
+    }
 }
 
 fn just_error<T>() -> EvaluationResult<T> {
@@ -202,5 +206,5 @@ mod tests {
         text = "The provider MAY define a mechanism to gracefully shutdown and dispose of resources."
     )]
     #[test]
-    fn shutdown_covered_by_drop_trait() {}
+    fn shutdown_covered_by_shutdown_method() {}
 }
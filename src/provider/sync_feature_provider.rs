@@ -0,0 +1,453 @@
+use std::{collections::HashMap, sync::Arc};
+
+use async_broadcast::Receiver;
+use async_trait::async_trait;
+
+use crate::{EvaluationContext, EvaluationResult, HookWrapper, StructValue, Value};
+
+use super::{
+    FeatureProvider, FlagDefinition, ProviderEvent, ProviderMetadata, ProviderStatus,
+    ResolutionDetails,
+};
+
+// ============================================================
+//  SyncFeatureProvider
+// ============================================================
+
+/// A [`FeatureProvider`] that never awaits, e.g. backed by an in-memory map, environment
+/// variables or files parsed at startup.
+///
+/// Register it wrapped in a [`SyncProvider`]; its flags are then resolved by a plain function
+/// call, without allocating a future per evaluation.
+///
+/// ```
+/// use open_feature::{
+///     provider::{ProviderMetadata, ResolutionDetails, SyncFeatureProvider, SyncProvider},
+///     EvaluationContext, EvaluationError, EvaluationErrorCode, EvaluationResult, OpenFeature,
+///     StructValue,
+/// };
+///
+/// struct EnvProvider {
+///     metadata: ProviderMetadata,
+/// }
+///
+/// impl EnvProvider {
+///     fn resolve<T>(
+///         &self,
+///         flag_key: &str,
+///         parse: fn(&str) -> Option<T>,
+///     ) -> EvaluationResult<ResolutionDetails<T>> {
+///         let error = |code| EvaluationError::builder().code(code).build();
+///
+///         let value = std::env::var(flag_key)
+///             .map_err(|_| error(EvaluationErrorCode::FlagNotFound))?;
+///
+///         parse(&value)
+///             .map(ResolutionDetails::new)
+///             .ok_or_else(|| error(EvaluationErrorCode::TypeMismatch))
+///     }
+/// }
+///
+/// impl SyncFeatureProvider for EnvProvider {
+///     fn metadata(&self) -> &ProviderMetadata {
+///         &self.metadata
+///     }
+///
+///     fn resolve_bool_value(
+///         &self,
+///         flag_key: &str,
+///         _: &EvaluationContext,
+///     ) -> EvaluationResult<ResolutionDetails<bool>> {
+///         self.resolve(flag_key, |value| value.parse().ok())
+///     }
+///
+///     // ...
+/// #   fn resolve_int_value(
+/// #       &self,
+/// #       flag_key: &str,
+/// #       _: &EvaluationContext,
+/// #   ) -> EvaluationResult<ResolutionDetails<i64>> {
+/// #       self.resolve(flag_key, |value| value.parse().ok())
+/// #   }
+/// #
+/// #   fn resolve_float_value(
+/// #       &self,
+/// #       flag_key: &str,
+/// #       _: &EvaluationContext,
+/// #   ) -> EvaluationResult<ResolutionDetails<f64>> {
+/// #       self.resolve(flag_key, |value| value.parse().ok())
+/// #   }
+/// #
+/// #   fn resolve_string_value(
+/// #       &self,
+/// #       flag_key: &str,
+/// #       _: &EvaluationContext,
+/// #   ) -> EvaluationResult<ResolutionDetails<String>> {
+/// #       self.resolve(flag_key, |value| Some(value.to_string()))
+/// #   }
+/// #
+/// #   fn resolve_struct_value(
+/// #       &self,
+/// #       flag_key: &str,
+/// #       _: &EvaluationContext,
+/// #   ) -> EvaluationResult<ResolutionDetails<StructValue>> {
+/// #       self.resolve(flag_key, |_| None)
+/// #   }
+/// }
+///
+/// # async fn example() {
+/// let mut api = OpenFeature::singleton_mut().await;
+/// api.set_provider(SyncProvider::new(EnvProvider {
+///     metadata: ProviderMetadata::new("Env"),
+/// }))
+/// .await;
+/// # }
+/// ```
+pub trait SyncFeatureProvider: Send + Sync + 'static {
+    /// Perform initialization logic with the global evaluation context, see
+    /// [`FeatureProvider::initialize`].
+    #[allow(unused_variables)]
+    fn initialize(&mut self, context: &EvaluationContext) {}
+
+    /// Respond to a change of the global evaluation context, see
+    /// [`FeatureProvider::on_context_changed`].
+    #[allow(unused_variables)]
+    fn on_context_changed(&self, old_context: &EvaluationContext, new_context: &EvaluationContext) {
+    }
+
+    /// Return the readiness of the provider, see [`FeatureProvider::status`].
+    fn status(&self) -> ProviderStatus {
+        ProviderStatus::Ready
+    }
+
+    /// Return the metadata of the provider, see [`FeatureProvider::metadata`].
+    fn metadata(&self) -> &ProviderMetadata;
+
+    /// Return the hooks of the provider, see [`FeatureProvider::hooks`].
+    fn hooks(&self) -> &[HookWrapper] {
+        &[]
+    }
+
+    /// Return a new receiver of the events of the provider, see
+    /// [`FeatureProvider::subscribe_events`].
+    fn subscribe_events(&self) -> Option<Receiver<ProviderEvent>> {
+        None
+    }
+
+    /// Resolve given `flag_key` as a bool value.
+    fn resolve_bool_value(
+        &self,
+        flag_key: &str,
+        evaluation_context: &EvaluationContext,
+    ) -> EvaluationResult<ResolutionDetails<bool>>;
+
+    /// Resolve given `flag_key` as an i64 value.
+    fn resolve_int_value(
+        &self,
+        flag_key: &str,
+        evaluation_context: &EvaluationContext,
+    ) -> EvaluationResult<ResolutionDetails<i64>>;
+
+    /// Resolve given `flag_key` as a f64 value.
+    fn resolve_float_value(
+        &self,
+        flag_key: &str,
+        evaluation_context: &EvaluationContext,
+    ) -> EvaluationResult<ResolutionDetails<f64>>;
+
+    /// Resolve given `flag_key` as a string value.
+    fn resolve_string_value(
+        &self,
+        flag_key: &str,
+        evaluation_context: &EvaluationContext,
+    ) -> EvaluationResult<ResolutionDetails<String>>;
+
+    /// Resolve given `flag_key` as a struct value.
+    fn resolve_struct_value(
+        &self,
+        flag_key: &str,
+        evaluation_context: &EvaluationContext,
+    ) -> EvaluationResult<ResolutionDetails<StructValue>>;
+
+    /// Resolve given `flag_key` out of the state cached for the global evaluation context, see
+    /// [`FeatureProvider::resolve_cached_value`].
+    #[allow(unused_variables)]
+    fn resolve_cached_value(
+        &self,
+        flag_key: &str,
+    ) -> Option<EvaluationResult<ResolutionDetails<Value>>> {
+        None
+    }
+
+    /// Resolve all the flags for `evaluation_context` at once, see
+    /// [`FeatureProvider::resolve_all`].
+    #[allow(unused_variables)]
+    fn resolve_all(
+        &self,
+        evaluation_context: &EvaluationContext,
+    ) -> Option<EvaluationResult<HashMap<String, ResolutionDetails<Value>>>> {
+        None
+    }
+
+    /// List the definitions of the flags of the provider, see [`FeatureProvider::list_flags`].
+    fn list_flags(&self) -> Option<EvaluationResult<Vec<FlagDefinition>>> {
+        None
+    }
+
+    /// Dispose of the resources of the provider, see [`FeatureProvider::shutdown`].
+    fn shutdown(&self) {}
+}
+
+// ============================================================
+//  SyncProvider
+// ============================================================
+
+/// The [`FeatureProvider`] adapter of a [`SyncFeatureProvider`].
+///
+/// Once bound, clients call the synchronous provider directly. The [`FeatureProvider`] methods
+/// are only used when the adapter is called on its own, e.g. by a wrapping provider.
+pub struct SyncProvider {
+    provider: Arc<dyn SyncFeatureProvider>,
+}
+
+impl SyncProvider {
+    /// Adapt `provider` to the [`FeatureProvider`] trait.
+    pub fn new(provider: impl SyncFeatureProvider) -> Self {
+        Self {
+            provider: Arc::new(provider),
+        }
+    }
+
+    /// Return the adapted provider, shared with the adapter once it is initialized.
+    pub(crate) fn get(&self) -> Arc<dyn SyncFeatureProvider> {
+        self.provider.clone()
+    }
+}
+
+#[async_trait]
+impl FeatureProvider for SyncProvider {
+    async fn initialize(&mut self, context: &EvaluationContext) {
+        // INFO: The provider is only shared within the crate, once bound
+        Arc::get_mut(&mut self.provider)
+            .expect("a provider is initialized before being shared")
+            .initialize(context);
+    }
+
+    async fn on_context_changed(
+        &self,
+        old_context: &EvaluationContext,
+        new_context: &EvaluationContext,
+    ) {
+        self.provider.on_context_changed(old_context, new_context);
+    }
+
+    fn status(&self) -> ProviderStatus {
+        self.provider.status()
+    }
+
+    fn metadata(&self) -> &ProviderMetadata {
+        self.provider.metadata()
+    }
+
+    fn hooks(&self) -> &[HookWrapper] {
+        self.provider.hooks()
+    }
+
+    fn subscribe_events(&self) -> Option<Receiver<ProviderEvent>> {
+        self.provider.subscribe_events()
+    }
+
+    async fn resolve_bool_value(
+        &self,
+        flag_key: &str,
+        evaluation_context: &EvaluationContext,
+    ) -> EvaluationResult<ResolutionDetails<bool>> {
+        self.provider
+            .resolve_bool_value(flag_key, evaluation_context)
+    }
+
+    async fn resolve_int_value(
+        &self,
+        flag_key: &str,
+        evaluation_context: &EvaluationContext,
+    ) -> EvaluationResult<ResolutionDetails<i64>> {
+        self.provider
+            .resolve_int_value(flag_key, evaluation_context)
+    }
+
+    async fn resolve_float_value(
+        &self,
+        flag_key: &str,
+        evaluation_context: &EvaluationContext,
+    ) -> EvaluationResult<ResolutionDetails<f64>> {
+        self.provider
+            .resolve_float_value(flag_key, evaluation_context)
+    }
+
+    async fn resolve_string_value(
+        &self,
+        flag_key: &str,
+        evaluation_context: &EvaluationContext,
+    ) -> EvaluationResult<ResolutionDetails<String>> {
+        self.provider
+            .resolve_string_value(flag_key, evaluation_context)
+    }
+
+    async fn resolve_struct_value(
+        &self,
+        flag_key: &str,
+        evaluation_context: &EvaluationContext,
+    ) -> EvaluationResult<ResolutionDetails<StructValue>> {
+        self.provider
+            .resolve_struct_value(flag_key, evaluation_context)
+    }
+
+    fn resolve_cached_value(
+        &self,
+        flag_key: &str,
+    ) -> Option<EvaluationResult<ResolutionDetails<Value>>> {
+        self.provider.resolve_cached_value(flag_key)
+    }
+
+    async fn resolve_all(
+        &self,
+        evaluation_context: &EvaluationContext,
+    ) -> Option<EvaluationResult<HashMap<String, ResolutionDetails<Value>>>> {
+        self.provider.resolve_all(evaluation_context)
+    }
+
+    async fn list_flags(&self) -> Option<EvaluationResult<Vec<FlagDefinition>>> {
+        self.provider.list_flags()
+    }
+
+    async fn shutdown(&self) {
+        self.provider.shutdown();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{EvaluationErrorCode, OpenFeature};
+
+    use super::*;
+
+    struct StaticProvider {
+        metadata: ProviderMetadata,
+        greeting: String,
+    }
+
+    impl SyncFeatureProvider for StaticProvider {
+        fn initialize(&mut self, context: &EvaluationContext) {
+            if let Some(targeting_key) = &context.targeting_key {
+                self.greeting = format!("Hello, {targeting_key}");
+            }
+        }
+
+        fn metadata(&self) -> &ProviderMetadata {
+            &self.metadata
+        }
+
+        fn resolve_bool_value(
+            &self,
+            _flag_key: &str,
+            _evaluation_context: &EvaluationContext,
+        ) -> EvaluationResult<ResolutionDetails<bool>> {
+            panic!("boom")
+        }
+
+        fn resolve_int_value(
+            &self,
+            _flag_key: &str,
+            _evaluation_context: &EvaluationContext,
+        ) -> EvaluationResult<ResolutionDetails<i64>> {
+            Ok(ResolutionDetails::new(42))
+        }
+
+        fn resolve_float_value(
+            &self,
+            _flag_key: &str,
+            _evaluation_context: &EvaluationContext,
+        ) -> EvaluationResult<ResolutionDetails<f64>> {
+            Ok(ResolutionDetails::new(4.2))
+        }
+
+        fn resolve_string_value(
+            &self,
+            _flag_key: &str,
+            _evaluation_context: &EvaluationContext,
+        ) -> EvaluationResult<ResolutionDetails<String>> {
+            Ok(ResolutionDetails::new(self.greeting.clone()))
+        }
+
+        fn resolve_struct_value(
+            &self,
+            _flag_key: &str,
+            _evaluation_context: &EvaluationContext,
+        ) -> EvaluationResult<ResolutionDetails<StructValue>> {
+            Ok(ResolutionDetails::new(StructValue::default()))
+        }
+
+        fn resolve_cached_value(
+            &self,
+            _flag_key: &str,
+        ) -> Option<EvaluationResult<ResolutionDetails<Value>>> {
+            Some(Ok(ResolutionDetails::new(Value::Int(42))))
+        }
+    }
+
+    fn provider() -> SyncProvider {
+        SyncProvider::new(StaticProvider {
+            metadata: ProviderMetadata::new("Static"),
+            greeting: "Hello".to_string(),
+        })
+    }
+
+    #[tokio::test]
+    async fn evaluate() {
+        let api = OpenFeature::builder()
+            .provider(provider())
+            .context(EvaluationContext::default().with_targeting_key("Bob"))
+            .build()
+            .await;
+        let client = api.create_client();
+
+        assert_eq!(client.get_int_value("flag", None, None).await, Ok(42));
+        assert_eq!(
+            client.get_string_value("flag", None, None).await,
+            Ok("Hello, Bob".to_string())
+        );
+        assert_eq!(
+            client
+                .get_bool_value("flag", None, None)
+                .await
+                .unwrap_err()
+                .code,
+            EvaluationErrorCode::General("Provider panicked".to_string())
+        );
+    }
+
+    #[tokio::test]
+    async fn adapt() {
+        let mut provider = provider();
+        provider.initialize(&EvaluationContext::default()).await;
+
+        assert_eq!(provider.metadata().name, "Static");
+        assert_eq!(
+            provider
+                .resolve_int_value("flag", &EvaluationContext::default())
+                .await
+                .unwrap()
+                .value,
+            42
+        );
+        assert_eq!(
+            provider
+                .resolve_cached_value("flag")
+                .unwrap()
+                .unwrap()
+                .value,
+            Value::Int(42)
+        );
+        assert!(provider.list_flags().await.is_none());
+    }
+}
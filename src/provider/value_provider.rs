@@ -0,0 +1,393 @@
+use std::collections::HashMap;
+
+use async_broadcast::Receiver;
+use async_trait::async_trait;
+
+use crate::{
+    EvaluationContext, EvaluationError, EvaluationErrorCode, EvaluationResult, HookWrapper,
+    StructValue, Type, Value,
+};
+
+use super::{
+    FeatureProvider, FlagDefinition, ProviderEvent, ProviderMetadata, ProviderStatus,
+    ResolutionDetails,
+};
+
+// ============================================================
+//  ValueProvider
+// ============================================================
+
+/// A provider resolving every flag type through a single method.
+///
+/// Every [`ValueProvider`] is a [`FeatureProvider`]: the resolved [`Value`] is converted to the
+/// type of the evaluation, and any other type is reported as
+/// [`EvaluationErrorCode::TypeMismatch`]. Ints are widened to floats as long as the conversion
+/// is exact.
+///
+/// ```
+/// use open_feature::{
+///     provider::{ProviderMetadata, ResolutionDetails, ValueProvider},
+///     EvaluationContext, EvaluationResult, OpenFeature, Type, Value,
+/// };
+///
+/// struct DarkModeProvider(ProviderMetadata);
+///
+/// #[open_feature::async_trait]
+/// impl ValueProvider for DarkModeProvider {
+///     fn metadata(&self) -> &ProviderMetadata {
+///         &self.0
+///     }
+///
+///     async fn resolve_value(
+///         &self,
+///         _flag_key: &str,
+///         _expected: Type,
+///         _evaluation_context: &EvaluationContext,
+///     ) -> EvaluationResult<ResolutionDetails<Value>> {
+///         Ok(ResolutionDetails::new(true))
+///     }
+/// }
+///
+/// # async fn example() {
+/// let mut api = OpenFeature::singleton_mut().await;
+/// api.set_provider(DarkModeProvider(ProviderMetadata::new("Dark Mode")))
+///     .await;
+/// # }
+/// ```
+#[async_trait]
+pub trait ValueProvider: Send + Sync + 'static {
+    /// Perform initialization logic with the global evaluation context, see
+    /// [`FeatureProvider::initialize`].
+    #[allow(unused_variables)]
+    async fn initialize(&mut self, context: &EvaluationContext) {}
+
+    /// Respond to a change of the global evaluation context, see
+    /// [`FeatureProvider::on_context_changed`].
+    #[allow(unused_variables)]
+    async fn on_context_changed(
+        &self,
+        old_context: &EvaluationContext,
+        new_context: &EvaluationContext,
+    ) {
+    }
+
+    /// Return the readiness of the provider, see [`FeatureProvider::status`].
+    fn status(&self) -> ProviderStatus {
+        ProviderStatus::Ready
+    }
+
+    /// Return the metadata of the provider, see [`FeatureProvider::metadata`].
+    fn metadata(&self) -> &ProviderMetadata;
+
+    /// Return the hooks of the provider, see [`FeatureProvider::hooks`].
+    fn hooks(&self) -> &[HookWrapper] {
+        &[]
+    }
+
+    /// Return a new receiver of the events of the provider, see
+    /// [`FeatureProvider::subscribe_events`].
+    fn subscribe_events(&self) -> Option<Receiver<ProviderEvent>> {
+        None
+    }
+
+    /// Resolve given `flag_key`, evaluated as an `expected` value.
+    async fn resolve_value(
+        &self,
+        flag_key: &str,
+        expected: Type,
+        evaluation_context: &EvaluationContext,
+    ) -> EvaluationResult<ResolutionDetails<Value>>;
+
+    /// Resolve given `flag_key` out of the state cached for the global evaluation context, see
+    /// [`FeatureProvider::resolve_cached_value`].
+    #[allow(unused_variables)]
+    fn resolve_cached_value(
+        &self,
+        flag_key: &str,
+    ) -> Option<EvaluationResult<ResolutionDetails<Value>>> {
+        None
+    }
+
+    /// Resolve all the flags for `evaluation_context` at once, see
+    /// [`FeatureProvider::resolve_all`].
+    #[allow(unused_variables)]
+    async fn resolve_all(
+        &self,
+        evaluation_context: &EvaluationContext,
+    ) -> Option<EvaluationResult<HashMap<String, ResolutionDetails<Value>>>> {
+        None
+    }
+
+    /// List the definitions of the flags of the provider, see [`FeatureProvider::list_flags`].
+    async fn list_flags(&self) -> Option<EvaluationResult<Vec<FlagDefinition>>> {
+        None
+    }
+
+    /// Dispose of the resources of the provider, see [`FeatureProvider::shutdown`].
+    async fn shutdown(&self) {}
+}
+
+#[async_trait]
+impl<P: ValueProvider> FeatureProvider for P {
+    async fn initialize(&mut self, context: &EvaluationContext) {
+        ValueProvider::initialize(self, context).await;
+    }
+
+    async fn on_context_changed(
+        &self,
+        old_context: &EvaluationContext,
+        new_context: &EvaluationContext,
+    ) {
+        ValueProvider::on_context_changed(self, old_context, new_context).await;
+    }
+
+    fn status(&self) -> ProviderStatus {
+        ValueProvider::status(self)
+    }
+
+    fn metadata(&self) -> &ProviderMetadata {
+        ValueProvider::metadata(self)
+    }
+
+    fn hooks(&self) -> &[HookWrapper] {
+        ValueProvider::hooks(self)
+    }
+
+    fn subscribe_events(&self) -> Option<Receiver<ProviderEvent>> {
+        ValueProvider::subscribe_events(self)
+    }
+
+    async fn resolve_bool_value(
+        &self,
+        flag_key: &str,
+        evaluation_context: &EvaluationContext,
+    ) -> EvaluationResult<ResolutionDetails<bool>> {
+        let details = self
+            .resolve_value(flag_key, Type::Bool, evaluation_context)
+            .await?;
+
+        convert(details, &Type::Bool, Value::as_bool)
+    }
+
+    async fn resolve_int_value(
+        &self,
+        flag_key: &str,
+        evaluation_context: &EvaluationContext,
+    ) -> EvaluationResult<ResolutionDetails<i64>> {
+        let details = self
+            .resolve_value(flag_key, Type::Int, evaluation_context)
+            .await?;
+
+        convert(details, &Type::Int, Value::as_i64)
+    }
+
+    async fn resolve_float_value(
+        &self,
+        flag_key: &str,
+        evaluation_context: &EvaluationContext,
+    ) -> EvaluationResult<ResolutionDetails<f64>> {
+        let details = self
+            .resolve_value(flag_key, Type::Float, evaluation_context)
+            .await?;
+
+        convert(details, &Type::Float, Value::as_f64_widened)
+    }
+
+    async fn resolve_string_value(
+        &self,
+        flag_key: &str,
+        evaluation_context: &EvaluationContext,
+    ) -> EvaluationResult<ResolutionDetails<String>> {
+        let details = self
+            .resolve_value(flag_key, Type::String, evaluation_context)
+            .await?;
+
+        convert(details, &Type::String, |value| {
+            value.as_str().map(ToString::to_string)
+        })
+    }
+
+    async fn resolve_struct_value(
+        &self,
+        flag_key: &str,
+        evaluation_context: &EvaluationContext,
+    ) -> EvaluationResult<ResolutionDetails<StructValue>> {
+        let details = self
+            .resolve_value(flag_key, Type::Struct, evaluation_context)
+            .await?;
+
+        convert(details, &Type::Struct, |value| value.as_struct().cloned())
+    }
+
+    fn resolve_cached_value(
+        &self,
+        flag_key: &str,
+    ) -> Option<EvaluationResult<ResolutionDetails<Value>>> {
+        ValueProvider::resolve_cached_value(self, flag_key)
+    }
+
+    async fn resolve_all(
+        &self,
+        evaluation_context: &EvaluationContext,
+    ) -> Option<EvaluationResult<HashMap<String, ResolutionDetails<Value>>>> {
+        ValueProvider::resolve_all(self, evaluation_context).await
+    }
+
+    async fn list_flags(&self) -> Option<EvaluationResult<Vec<FlagDefinition>>> {
+        ValueProvider::list_flags(self).await
+    }
+
+    async fn shutdown(&self) {
+        ValueProvider::shutdown(self).await;
+    }
+}
+
+/// Convert the resolved value of `details` to the `expected` type.
+fn convert<T>(
+    details: ResolutionDetails<Value>,
+    expected: &Type,
+    convert: impl FnOnce(&Value) -> Option<T>,
+) -> EvaluationResult<ResolutionDetails<T>> {
+    let Some(value) = convert(&details.value) else {
+        return Err(EvaluationError::builder()
+            .code(EvaluationErrorCode::TypeMismatch)
+            .message(format!(
+                "Expected {expected} but resolved {}",
+                details.value.get_type()
+            ))
+            .build());
+    };
+
+    Ok(ResolutionDetails {
+        value,
+        variant: details.variant,
+        reason: details.reason,
+        flag_metadata: details.flag_metadata,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{EvaluationReason, OpenFeature};
+
+    use super::*;
+
+    struct StaticProvider {
+        metadata: ProviderMetadata,
+        value: Value,
+    }
+
+    #[async_trait]
+    impl ValueProvider for StaticProvider {
+        fn metadata(&self) -> &ProviderMetadata {
+            &self.metadata
+        }
+
+        async fn resolve_value(
+            &self,
+            _flag_key: &str,
+            _expected: Type,
+            _evaluation_context: &EvaluationContext,
+        ) -> EvaluationResult<ResolutionDetails<Value>> {
+            Ok(ResolutionDetails::builder()
+                .value(self.value.clone())
+                .variant("on")
+                .reason(EvaluationReason::Static)
+                .build())
+        }
+
+        fn resolve_cached_value(
+            &self,
+            _flag_key: &str,
+        ) -> Option<EvaluationResult<ResolutionDetails<Value>>> {
+            Some(Ok(ResolutionDetails::new(self.value.clone())))
+        }
+    }
+
+    fn provider(value: impl Into<Value>) -> StaticProvider {
+        StaticProvider {
+            metadata: ProviderMetadata::new("Static"),
+            value: value.into(),
+        }
+    }
+
+    #[tokio::test]
+    async fn resolve_typed_values() {
+        let api = OpenFeature::builder()
+            .provider(provider("hello"))
+            .build()
+            .await;
+        let client = api.create_client();
+
+        let details = client.get_string_details("flag", None, None).await.unwrap();
+        assert_eq!(details.value, "hello");
+        assert_eq!(details.variant.as_deref(), Some("on"));
+        assert_eq!(details.reason, Some(EvaluationReason::Static));
+
+        let error = client.get_bool_value("flag", None, None).await.unwrap_err();
+        assert_eq!(error.code, EvaluationErrorCode::TypeMismatch);
+        assert_eq!(
+            error.message.as_deref(),
+            Some("Expected bool but resolved string")
+        );
+    }
+
+    #[tokio::test]
+    async fn forward_optional_methods() {
+        let provider = provider(42);
+
+        assert_eq!(
+            FeatureProvider::resolve_cached_value(&provider, "flag")
+                .unwrap()
+                .unwrap()
+                .value,
+            Value::Int(42)
+        );
+        assert!(FeatureProvider::subscribe_events(&provider).is_none());
+        assert!(FeatureProvider::list_flags(&provider).await.is_none());
+        assert!(
+            FeatureProvider::resolve_all(&provider, &EvaluationContext::default())
+                .await
+                .is_none()
+        );
+    }
+
+    #[tokio::test]
+    async fn widen_int_to_float() {
+        let context = EvaluationContext::default();
+
+        assert_eq!(
+            provider(42)
+                .resolve_float_value("flag", &context)
+                .await
+                .unwrap()
+                .value
+                .to_string(),
+            "42"
+        );
+        assert_eq!(
+            provider(Value::Int(1 << f64::MANTISSA_DIGITS))
+                .resolve_float_value("flag", &context)
+                .await
+                .unwrap_err()
+                .code,
+            EvaluationErrorCode::TypeMismatch
+        );
+        assert_eq!(
+            provider(i64::MIN)
+                .resolve_float_value("flag", &context)
+                .await
+                .unwrap_err()
+                .code,
+            EvaluationErrorCode::TypeMismatch
+        );
+        assert_eq!(
+            provider(4.2)
+                .resolve_int_value("flag", &context)
+                .await
+                .unwrap_err()
+                .code,
+            EvaluationErrorCode::TypeMismatch
+        );
+    }
+}
@@ -1,4 +1,9 @@
-use crate::{EvaluationError, EvaluationResult, StructValue, Value};
+use time::format_description::well_known::Rfc3339;
+
+use crate::{
+    EvaluationContext, EvaluationContextFieldValue, EvaluationError, EvaluationResult, Redaction,
+    StructValue, Value,
+};
 
 impl TryFrom<serde_json::Value> for Value {
     type Error = EvaluationError;
@@ -16,6 +21,32 @@ impl TryFrom<&serde_json::Value> for Value {
     }
 }
 
+impl From<Value> for serde_json::Value {
+    fn from(value: Value) -> Self {
+        Self::from(&value)
+    }
+}
+
+/// Non-finite floats are converted to `null`, as JSON has no representation for them.
+impl From<&Value> for serde_json::Value {
+    fn from(value: &Value) -> Self {
+        match value {
+            Value::Bool(value) => (*value).into(),
+            Value::Int(value) => (*value).into(),
+            Value::Float(value) => (*value).into(),
+            Value::String(value) => value.clone().into(),
+            Value::Array(array) => array.iter().map(Self::from).collect(),
+            Value::Struct(value) => Self::Object(
+                value
+                    .fields
+                    .iter()
+                    .map(|(key, value)| (key.clone(), value.into()))
+                    .collect(),
+            ),
+        }
+    }
+}
+
 fn json_value_to_value(value: &serde_json::Value) -> EvaluationResult<Value> {
     match value {
         serde_json::Value::Bool(value) => Ok(Value::Bool(*value)),
@@ -48,9 +79,42 @@ fn json_value_to_value(value: &serde_json::Value) -> EvaluationResult<Value> {
     }
 }
 
+impl EvaluationContext {
+    /// Export `self` as a flat JSON object, with private fields redacted according to
+    /// `redaction`.
+    ///
+    /// The targeting key is exported as `targetingKey`, date-times as RFC 3339 strings. Struct
+    /// fields are opaque and left out.
+    pub fn to_json(&self, redaction: &Redaction) -> serde_json::Value {
+        let context = self.redacted(redaction);
+        let mut object = serde_json::Map::new();
+
+        if let Some(targeting_key) = context.targeting_key {
+            object.insert("targetingKey".to_string(), targeting_key.into());
+        }
+
+        for (key, value) in context.custom_fields {
+            let value = match value {
+                EvaluationContextFieldValue::Bool(value) => value.into(),
+                EvaluationContextFieldValue::Int(value) => value.into(),
+                EvaluationContextFieldValue::Float(value) => value.into(),
+                EvaluationContextFieldValue::String(value) => value.into(),
+                EvaluationContextFieldValue::DateTime(value) => value
+                    .format(&Rfc3339)
+                    .map_or(serde_json::Value::Null, Into::into),
+                EvaluationContextFieldValue::Struct(_) => continue,
+            };
+
+            object.insert(key, value);
+        }
+
+        serde_json::Value::Object(object)
+    }
+}
+
 #[cfg(test)]
 mod tests {
-    use crate::{StructValue, Value};
+    use crate::{EvaluationContext, Redaction, StructValue, Value, REDACTED};
 
     #[test]
     fn convert_data() {
@@ -115,4 +179,27 @@ mod tests {
 
         assert!(result.is_err());
     }
+
+    #[test]
+    fn export_context_redacted() {
+        let context = EvaluationContext::default()
+            .with_targeting_key("user-1")
+            .with_custom_field("plan", "pro")
+            .with_custom_field("age", 42)
+            .with_private_field("email", "user@example.com");
+
+        assert_eq!(
+            context.to_json(&Redaction::Mask),
+            serde_json::json!({
+                "targetingKey": "user-1",
+                "plan": "pro",
+                "age": 42,
+                "email": REDACTED,
+            })
+        );
+
+        let hashed = context.to_json(&Redaction::hash("salt"));
+        assert_ne!(hashed["email"], "user@example.com");
+        assert_eq!(hashed["email"].as_str().unwrap().len(), 64);
+    }
 }